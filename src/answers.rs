@@ -0,0 +1,119 @@
+use std::collections::HashMap;
+use std::fmt;
+use std::fs;
+use std::io;
+
+use crate::aoc::DayId;
+use crate::solution::{Answer, Solution};
+
+/// Known answers for a set of puzzle inputs, read from a small TOML file:
+///
+/// ```toml
+/// [2020.day5]
+/// part1 = 822
+/// part2 = "some text answer"
+/// ```
+#[derive(Debug, Default)]
+pub struct Answers {
+    days: HashMap<DayId, [Option<Answer>; 2]>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Verdict {
+    Pass,
+    Fail { expected: Answer },
+    Unknown,
+}
+
+impl fmt::Display for Verdict {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Verdict::Pass => write!(f, "PASS"),
+            Verdict::Fail { expected } => write!(f, "FAIL, expected {}", expected),
+            Verdict::Unknown => write!(f, "UNKNOWN"),
+        }
+    }
+}
+
+impl Answers {
+    /// Loads answers from `path`, a missing file simply has no known answers
+    pub fn load(path: &str) -> Result<Answers, String> {
+        match fs::read_to_string(path) {
+            Ok(content) => Self::parse(&content).map_err(|err| format!("{}: {}", path, err)),
+            Err(err) if err.kind() == io::ErrorKind::NotFound => Ok(Answers::default()),
+            Err(err) => Err(format!("{}: {}", path, err)),
+        }
+    }
+
+    pub fn parse(content: &str) -> Result<Answers, String> {
+        let mut answers = Answers::default();
+        let mut day = None;
+
+        for (i, line) in content.lines().enumerate() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+
+            let error = |msg: &str| format!("line {}: {} '{}'", i + 1, msg, line);
+
+            if let Some(section) = line.strip_prefix('[').and_then(|l| l.strip_suffix(']')) {
+                let id = parse_section(section.trim())
+                    .ok_or_else(|| error("expected a [year.dayN] section, found"))?;
+                day = Some(id);
+                continue;
+            }
+
+            let day = day.ok_or_else(|| error("answer outside of a [year.dayN] section"))?;
+            let (key, value) = line
+                .split_once('=')
+                .ok_or_else(|| error("expected 'partN = answer', found"))?;
+            let part = match key.trim() {
+                "part1" => 0,
+                "part2" => 1,
+                _ => return Err(error("unknown key in")),
+            };
+            let value = parse_answer(value.trim()).ok_or_else(|| error("invalid answer in"))?;
+
+            answers.days.entry(day).or_default()[part] = Some(value);
+        }
+
+        Ok(answers)
+    }
+
+    /// Adds the answers of another file, replacing any known for the same day
+    pub fn extend(&mut self, other: Answers) {
+        self.days.extend(other.days);
+    }
+
+    /// Checks both parts of a solution against the known answers for `day`
+    pub fn verify(&self, day: DayId, solution: &Solution) -> [Verdict; 2] {
+        let expected = self.days.get(&day);
+        let verdict = |part: usize, answer: &Answer| {
+            let expected = expected.and_then(|e| e[part].as_ref());
+            match (answer, expected) {
+                (Answer::Unsolved, _) | (_, None) => Verdict::Unknown,
+                (answer, Some(expected)) if answer == expected => Verdict::Pass,
+                (_, Some(expected)) => Verdict::Fail {
+                    expected: expected.clone(),
+                },
+            }
+        };
+
+        [verdict(0, &solution.part1), verdict(1, &solution.part2)]
+    }
+}
+
+fn parse_section(section: &str) -> Option<DayId> {
+    let (year, day) = section.split_once('.')?;
+    let day = day.strip_prefix("day")?;
+    Some(DayId::new(year.parse().ok()?, day.parse().ok()?))
+}
+
+fn parse_answer(value: &str) -> Option<Answer> {
+    if let Some(text) = value.strip_prefix('"').and_then(|v| v.strip_suffix('"')) {
+        return Some(Answer::Text(text.to_string()));
+    }
+
+    value.replace('_', "").parse().ok().map(Answer::Number)
+}
@@ -1,35 +1,106 @@
-pub mod aoc1;
-pub mod aoc2;
-pub mod aoc3;
-pub mod aoc4;
-pub mod aoc5;
-pub mod aoc6;
-pub mod aoc7;
-pub mod aoc8;
-pub mod aoc9;
-pub mod aoc10;
-pub mod aoc11;
-pub mod aoc12;
-pub mod aoc13;
-pub mod aoc14;
-pub mod aoc15;
-pub mod aoc16;
-pub mod aoc17;
-
-pub use aoc1::*;
-pub use aoc2::*;
-pub use aoc3::*;
-pub use aoc4::*;
-pub use aoc5::*;
-pub use aoc6::*;
-pub use aoc7::*;
-pub use aoc8::*;
-pub use aoc9::*;
-pub use aoc10::*;
-pub use aoc11::*;
-pub use aoc12::*;
-pub use aoc13::*;
-pub use aoc14::*;
-pub use aoc15::*;
-pub use aoc16::*;
-pub use aoc17::*;
\ No newline at end of file
+use std::fmt;
+
+use crate::{DayRunner, Generator, Reporter, Validator};
+
+pub mod y2020;
+
+/// Number of puzzles in an Advent of Code event
+pub const LAST_DAY: u32 = 25;
+
+/// The first year Advent of Code was held, day specs from here on are taken as years
+pub const FIRST_YEAR: u16 = 2015;
+
+/// A puzzle, as the same day number is a different puzzle every year
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct DayId {
+    pub year: u16,
+    pub day: u32,
+}
+
+impl DayId {
+    pub const fn new(year: u16, day: u32) -> DayId {
+        DayId { year, day }
+    }
+}
+
+impl fmt::Display for DayId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} day {}", self.year, self.day)
+    }
+}
+
+pub struct Day {
+    pub day: u32,
+    pub title: &'static str,
+    pub runner: DayRunner,
+    pub validator: Validator,
+    /// Makes random inputs for stress testing, for the days that have one
+    pub generator: Option<Generator>,
+    /// Reports on every piece of the input, for the days that have one
+    pub reporter: Option<Reporter>,
+}
+
+impl Day {
+    const fn new(day: u32, title: &'static str, runner: DayRunner, validator: Validator) -> Day {
+        Day {
+            day,
+            title,
+            runner,
+            validator,
+            generator: None,
+            reporter: None,
+        }
+    }
+
+    const fn with_generator(self, generator: Generator) -> Day {
+        Day {
+            generator: Some(generator),
+            ..self
+        }
+    }
+
+    const fn with_reporter(self, reporter: Reporter) -> Day {
+        Day {
+            reporter: Some(reporter),
+            ..self
+        }
+    }
+}
+
+/// The solved days of one event
+pub struct Year {
+    pub year: u16,
+    pub days: &'static [Day],
+}
+
+impl Year {
+    pub fn ids(&self) -> impl Iterator<Item = DayId> {
+        let year = self.year;
+        self.days.iter().map(move |day| DayId::new(year, day.day))
+    }
+}
+
+/// Every year with solved days, in order.
+/// A new year only needs its module declared above and an entry here.
+pub const YEARS: &[Year] = &[Year {
+    year: 2020,
+    days: y2020::DAYS,
+}];
+
+pub fn find_year(year: u16) -> Option<&'static Year> {
+    YEARS.iter().find(|y| y.year == year)
+}
+
+/// The latest year, which days given without one belong to
+pub fn latest_year() -> u16 {
+    YEARS.last().expect("at least one year").year
+}
+
+pub fn find_day(id: DayId) -> Option<&'static Day> {
+    find_year(id.year)?.days.iter().find(|d| d.day == id.day)
+}
+
+/// Days of an event that have no registered solver yet
+pub fn missing_days(year: u16) -> impl Iterator<Item = u32> {
+    (1..=LAST_DAY).filter(move |&day| find_day(DayId::new(year, day)).is_none())
+}
@@ -0,0 +1,76 @@
+use crate::bench::{timed, Phase};
+use crate::error::{parse_lines, parse_value, Error, Result};
+use crate::ksum;
+use crate::output::Table;
+use crate::solution::Solution;
+use crate::validate;
+
+/// The sum the entries of the expense report have to add up to
+const TARGET: i64 = 2020;
+
+pub fn aoc_1(input: &str) -> Result<Solution> {
+    let nums: Vec<i64> = timed(Phase::Parse, || parse_lines(input.lines(), parse_value))?;
+
+    let part1 = timed(Phase::Part1, || product_of_sum(&nums, 2))?;
+    let part2 = timed(Phase::Part2, || product_of_sum(&nums, 3))?;
+
+    Ok(Solution::new(part1, part2))
+}
+
+/// The product of the `k` entries that sum to the target
+fn product_of_sum(nums: &[i64], k: usize) -> Result<i64> {
+    ksum::find(nums, k, TARGET)
+        .map(|entries| entries.iter().product())
+        .ok_or_else(|| Error::new(format!("no {} entries sum to {}", k, TARGET)))
+}
+
+/// Each line is an expense report entry
+pub fn validate(input: &str) -> Vec<Error> {
+    validate::numbers(input)
+}
+
+/// Every combination of entries that sums to the target in each part, a row each.
+/// A part with more than one is ambiguous, its answer depending on which combination is picked.
+pub fn report(input: &str) -> Result<Table> {
+    let nums: Vec<i64> = parse_lines(input.lines(), parse_value)?;
+
+    let mut table = Table::new(vec![
+        "part", "solution", "lines", "values", "product", "note",
+    ]);
+    for &(part, k) in &[(1, 2), (2, 3)] {
+        let combinations = ksum::find_all(&nums, k, TARGET);
+        let note = match combinations.len() {
+            0 => format!("no {} entries sum to {}", k, TARGET),
+            1 => String::new(),
+            n => format!("ambiguous, {} combinations sum to {}", n, TARGET),
+        };
+
+        if combinations.is_empty() {
+            table.push(vec![
+                part.to_string(),
+                "-".to_string(),
+                String::new(),
+                String::new(),
+                String::new(),
+                note.clone(),
+            ]);
+        }
+        for (i, combination) in combinations.iter().enumerate() {
+            let lines: Vec<String> = combination
+                .indices
+                .iter()
+                .map(|i| (i + 1).to_string())
+                .collect();
+            let values: Vec<String> = combination.values.iter().map(|v| v.to_string()).collect();
+            table.push(vec![
+                part.to_string(),
+                format!("{} of {}", i + 1, combinations.len()),
+                lines.join(", "),
+                values.join(" + "),
+                combination.values.iter().product::<i64>().to_string(),
+                note.clone(),
+            ]);
+        }
+    }
+    Ok(table)
+}
@@ -0,0 +1,83 @@
+use std::{
+    collections::HashSet,
+    ops::RangeInclusive,
+    thread::current,
+};
+
+use crate::error::{parse_lines, parse_value, Error, Result};
+use crate::solution::Solution;
+use crate::validate;
+
+const WINDOW_SIZE: usize = 25;
+const RANGE: RangeInclusive<i16> = 1..=3;
+
+pub fn aoc_10(input: &str) -> Result<Solution> {
+    let mut lines: Vec<i16> = parse_lines(input.lines(), parse_value)?;
+    if lines.is_empty() {
+        return Err(Error::new("empty input"));
+    }
+
+    lines.sort();
+
+    let ones_threes = lines
+        .windows(2)
+        .map(|v| v[1] - v[0])
+        .fold(add_counter(lines[0], (0, 1)), |a, v| add_counter(v, a));
+
+    // Part 2, every way to chain the adapters starting from the outlet at 0
+    let mut joltages = vec![0];
+    joltages.extend(&lines);
+
+    let mut paths = Vec::new();
+    for i in 0..joltages.len() {
+        let current = joltages[i];
+        let mut vec = Vec::new();
+
+        for j in i + 1..joltages.len() {
+            let next = joltages[j] - current;
+            if RANGE.contains(&next) {
+                vec.push(j);
+            }
+        }
+        paths.push(vec);
+    }
+
+    let permutes = count_paths(&paths);
+
+    Ok(Solution::new(ones_threes.0 as u32 * ones_threes.1 as u32, permutes))
+}
+
+/// Each line is the joltage of an adapter, and no two adapters are the same
+pub fn validate(input: &str) -> Vec<Error> {
+    let mut problems = validate::numbers(input);
+    let mut seen = HashSet::new();
+    for (i, line) in input.lines().enumerate() {
+        if !seen.insert(line.trim()) {
+            problems.push(Error::invalid("repeated adapter", line).at_line(i + 1));
+        }
+    }
+    problems
+}
+
+/// Counts the paths from the first adapter to the last, working back from the end
+fn count_paths(paths: &[Vec<usize>]) -> usize {
+    let mut counts = vec![0usize; paths.len()];
+    for index in (0..paths.len()).rev() {
+        counts[index] = if index == paths.len() - 1 {
+            1
+        } else {
+            paths[index].iter().map(|&next| counts[next]).sum()
+        };
+    }
+    counts[0]
+}
+
+#[inline]
+fn add_counter(diff: i16, mut counter: (u16, u16)) -> (u16, u16) {
+    match diff {
+        1 => counter.0 += 1,
+        3 => counter.1 += 1,
+        _ => (),
+    }
+    counter
+}
@@ -0,0 +1,280 @@
+use std::cell::RefCell;
+use std::collections::HashSet;
+use std::rc::Rc;
+
+use crate::bench::{timed, Phase};
+use crate::error::{Error, Result};
+use crate::generate::{self, Rng};
+use crate::metrics;
+use crate::solution::Solution;
+use crate::validate;
+
+pub fn aoc_11(input: &str) -> Result<Solution> {
+    let part1 = timed(Phase::Part1, || run_solver(input, update_state_p1))?;
+    let part2 = timed(Phase::Part2, || run_solver(input, update_state_p2))?;
+
+    Ok(Solution::new(part1, part2))
+}
+
+/// The layout is a rectangle of floor, empty seats and occupied seats
+pub fn validate(input: &str) -> Vec<Error> {
+    validate::grid(input, ".L#")
+}
+
+/// A random layout `size` seats wide and high, 95 by default, with floor in every seventh place or so
+pub fn generate(rng: &mut Rng, size: Option<usize>) -> String {
+    let size = size.unwrap_or(95).max(1);
+    let mut layout: Vec<u8> = (0..size * size)
+        .map(|_| if rng.chance(0.15) { b'.' } else { b'L' })
+        .collect();
+    let to_input = |layout: &[u8]| {
+        generate::lines(
+            layout
+                .chunks(size)
+                .map(|row| String::from_utf8_lossy(row).into_owned()),
+        )
+    };
+
+    // Random layouts end up with patches of seats that flip back and forth forever,
+    // which puzzle inputs never have. Some of those seats become floor, breaking the patches up,
+    // until the layout settles under both rules.
+    loop {
+        let input = to_input(&layout);
+        let cycling: Vec<usize> = [update_state_p1 as Rule, update_state_p2]
+            .iter()
+            .flat_map(|&rule| cycling_seats(&input, rule))
+            .collect();
+        if cycling.is_empty() {
+            return input;
+        }
+        layout[cycling[0]] = b'.';
+        for i in cycling {
+            if rng.chance(0.05) {
+                layout[i] = b'.';
+            }
+        }
+    }
+}
+
+/// The seats that keep changing when `rule` is run over `layout`, none if it settles
+fn cycling_seats(layout: &str, rule: Rule) -> Vec<usize> {
+    let mut grid = Grid::new(layout).expect("generated layouts are valid");
+    let mut seen = HashSet::new();
+    while grid.step(rule) {
+        let seats = grid.content.borrow().clone();
+        if !seen.insert(seats.clone()) {
+            grid.step(rule);
+            let next = grid.content.borrow();
+            return (0..seats.len()).filter(|&i| seats[i] != next[i]).collect();
+        }
+    }
+    Vec::new()
+}
+
+const OFFSETS: &[(i32, i32)] = &[
+    (-1, -1),
+    (0, -1),
+    (1, -1),
+    (-1, 0),
+    (1, 0),
+    (-1, 1),
+    (0, 1),
+    (1, 1),
+];
+
+/// Decides the next state of the seat at an index from the current grid
+pub type Rule = fn(&Grid, usize) -> State;
+
+#[derive(Eq, PartialEq, Hash, Copy, Clone, Debug)]
+pub enum State {
+    Floor,
+    Empty,
+    Occupied,
+}
+
+/// The seat layout, double buffered so a round can read one layout while writing the next
+pub struct Grid {
+    size: (usize, usize),
+    content: Rc<RefCell<Vec<State>>>,
+    swapchain: Rc<RefCell<Vec<State>>>,
+
+    content1: Rc<RefCell<Vec<State>>>,
+    content2: Rc<RefCell<Vec<State>>>,
+    backbuffer: i8,
+}
+
+impl Grid {
+    pub fn new(input: &str) -> Result<Grid> {
+        let lines: Vec<&str> = input.lines().collect();
+        let width = match lines.first() {
+            Some(line) => line.len(),
+            None => return Err(Error::new("empty input")),
+        };
+
+        let mut grid1 = Vec::new();
+        let mut grid2 = Vec::new();
+        for (i, line) in lines.iter().enumerate() {
+            if line.len() != width {
+                return Err(Error::invalid("row width differs from the first", line).at_line(i + 1));
+            }
+
+            for c in line.chars() {
+                let s = match c {
+                    '.' => State::Floor,
+                    'L' => State::Empty,
+                    '#' => State::Occupied,
+                    _ => return Err(Error::invalid("unknown seat", &c.to_string()).at_line(i + 1)),
+                };
+                grid1.push(s);
+                grid2.push(s);
+            }
+        }
+
+        let swapchain = Rc::new(RefCell::new(grid2));
+        let grid = Rc::new(RefCell::new(grid1));
+
+        Ok(Grid {
+            content: grid.clone(),
+            swapchain: swapchain.clone(),
+            content1: grid.clone(),
+            content2: swapchain.clone(),
+            size: (width, lines.len()),
+            backbuffer: 1,
+        })
+    }
+
+    pub fn size(&self) -> (usize, usize) {
+        self.size
+    }
+
+    pub fn get(&self, x: usize, y: usize) -> Option<State> {
+        if x >= self.size.0 || y >= self.size.1 {
+            return None;
+        }
+        Some(self.content.borrow()[index_from_pos((x as i32, y as i32), self.size)])
+    }
+
+    pub fn occupied(&self) -> usize {
+        self.content
+            .borrow()
+            .iter()
+            .filter(|&&v| v == State::Occupied)
+            .count()
+    }
+
+    /// Runs a single round of `rule` over every seat, returning whether any seat changed
+    pub fn step(&mut self, rule: Rule) -> bool {
+        let content_len = self.content.borrow().len();
+
+        let mut updated = 0;
+        for i in 0..content_len {
+            let state = self.content.borrow()[i];
+            if state == State::Floor {
+                continue;
+            }
+
+            // Every seat is written, the back buffer still holds the round before last
+            let next_state = (rule)(self, i);
+            self.swapchain.borrow_mut()[i] = next_state;
+            if state != next_state {
+                updated += 1;
+            }
+        }
+
+        metrics::count("generations", 1);
+        metrics::count("cells_updated", updated);
+        self.swap_contents();
+        updated > 0
+    }
+
+    pub fn swap_contents(&mut self) {
+        match self.backbuffer {
+            1 => {
+                assert_eq!(self.content, self.content1);
+                assert_eq!(self.swapchain, self.content2);
+
+                self.content = self.content2.clone();
+                self.swapchain = self.content1.clone();
+            }
+            -1 => {
+                assert_eq!(self.content, self.content2);
+                assert_eq!(self.swapchain, self.content1);
+
+                self.content = self.content1.clone();
+                self.swapchain = self.content2.clone();
+            }
+            _ => (),
+        }
+        self.backbuffer *= -1;
+    }
+}
+
+fn run_solver(input: &str, rule: Rule) -> Result<u32> {
+    let mut grid = Grid::new(input)?;
+    while grid.step(rule) {}
+
+    Ok(grid.occupied() as u32)
+}
+
+fn pos_from_index(index: usize, size: (usize, usize)) -> (i32, i32) {
+    ((index % size.0) as i32, (index / size.0) as i32)
+}
+fn add_pos(pos1: (i32, i32), pos2: (i32, i32)) -> (i32, i32) {
+    (pos1.0 + pos2.0, pos1.1 + pos2.1)
+}
+fn index_from_pos(pos: (i32, i32), size: (usize, usize)) -> usize {
+    (pos.1 * size.0 as i32 + pos.0) as usize
+}
+fn pos_in_bounds(pos: (i32, i32), size: (usize, usize)) -> bool {
+    pos.0 >= 0 && pos.1 >= 0 && pos.0 < size.0 as i32 && pos.1 < size.1 as i32
+}
+
+/// Seats empty when 4 or more adjacent seats are occupied
+pub fn update_state_p1(grid: &Grid, index: usize) -> State {
+    let state = grid.content.borrow()[index];
+
+    let mut occupied = 0;
+    for &offset in OFFSETS {
+        let pos = add_pos(pos_from_index(index, grid.size), offset);
+        if !pos_in_bounds(pos, grid.size) {
+            continue;
+        }
+        let pos = index_from_pos(pos, grid.size);
+        if grid.content.borrow()[pos] == State::Occupied {
+            occupied += 1;
+        }
+    }
+
+    match state {
+        State::Occupied if occupied >= 4 => State::Empty,
+        State::Empty if occupied == 0 => State::Occupied,
+        _ => state,
+    }
+}
+
+/// Seats empty when 5 or more of the first seats visible in each direction are occupied
+pub fn update_state_p2(grid: &Grid, index: usize) -> State {
+    let state = grid.content.borrow()[index];
+
+    let mut occupied = 0;
+    for &offset in OFFSETS {
+        let mut pos = add_pos(pos_from_index(index, grid.size), offset);
+        while pos_in_bounds(pos, grid.size) {
+            let index = index_from_pos(pos, grid.size);
+            let grid_state = grid.content.borrow()[index];
+            if grid_state == State::Occupied {
+                occupied += 1;
+            }
+            if grid_state != State::Floor {
+                break;
+            }
+            pos = add_pos(pos, offset);
+        }
+    }
+
+    match state {
+        State::Occupied if occupied >= 5 => State::Empty,
+        State::Empty if occupied == 0 => State::Occupied,
+        _ => state,
+    }
+}
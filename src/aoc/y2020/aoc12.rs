@@ -1,43 +1,71 @@
 use std::cell::RefCell;
 use std::{f32, i32};
-use std::{
-    fs::File,
-    io::{BufRead, BufReader},
-    rc::Rc,
-};
-
-pub fn aoc_12(reader: BufReader<File>) -> String {
-    let lines: Vec<String> = reader.lines().map(|l| l.unwrap()).collect();
-
-    // Benching
-    let sw = std::time::Instant::now();
-    for _ in 0..10000 {
-        let _ = compact_solution_1(&lines);
+use std::rc::Rc;
+
+use crate::bench::{timed, Phase};
+use crate::error::{parse_lines, parse_value, Error, Result};
+use crate::generate::{self, Rng};
+use crate::solution::Solution;
+use crate::validate::Problems;
+
+pub fn aoc_12(input: &str) -> Result<Solution> {
+    let ops = timed(Phase::Parse, || parse_lines(input.lines(), parse_compact))?;
+
+    let part1 = timed(Phase::Part1, || compact_solution_1(&ops));
+    let part2 = timed(Phase::Part2, || compact_solution_2(&ops));
+
+    Ok(Solution::new(part1, part2))
+}
+
+/// Each line is an action and a value such as `F10`, turning only by multiples of 90 degrees
+pub fn validate(input: &str) -> Vec<Error> {
+    let mut problems = Problems::new();
+    for (i, line) in input.lines().enumerate() {
+        let action = match line.chars().next() {
+            Some(action) => action,
+            None => {
+                problems.line(i + 1, "empty instruction", line);
+                continue;
+            }
+        };
+        let (action_text, val) = line.split_at(action.len_utf8());
+
+        if !"NESWLRF".contains(action) {
+            problems.at(i + 1, line, action_text, "unknown action");
+        }
+        let val = problems.value::<i32>(i + 1, line, val);
+        if let (Some(val), 'L') | (Some(val), 'R') = (val, action) {
+            if !matches!(val, 90 | 180 | 270) {
+                problems.at(i + 1, line, line, "turns must be 90, 180 or 270 degrees");
+            }
+        }
     }
-    let part1_time = sw.elapsed().as_nanos() / 10000;
+    problems.into_errors()
+}
+
+/// `size` random navigation instructions, 773 by default, with values in the ranges of real inputs
+pub fn generate(rng: &mut Rng, size: Option<usize>) -> String {
+    generate::lines((0..size.unwrap_or(773)).map(|_| match rng.below(7) {
+        0..=3 => format!("{}{}", rng.pick(&['N', 'E', 'S', 'W']), rng.range(1..=5)),
+        4 => format!("F{}", rng.range(1..=100)),
+        _ => format!("{}{}", rng.pick(&['L', 'R']), rng.pick(&[90, 180, 270])),
+    }))
+}
 
-    let sw = std::time::Instant::now();
-    for _ in 0..10000 {
-        let _ = compact_solution_2(&lines);
+/// Splits a line into its single letter action and its value
+fn split_action(line: &str) -> Result<(&str, i32)> {
+    if line.is_empty() {
+        return Err(Error::new("empty instruction"));
     }
-    let part2_time = sw.elapsed().as_nanos() / 10000;
-
-    // // Solving
-    let part1 = compact_solution_1(&lines);
-    assert_eq!(904, part1);
-    let part2 = compact_solution_2(&lines);
-    assert_eq!(18747, part2);
-
-    format!(
-        "P1 (~{}ns): {}\n\tP2 (~{}ns): {}",
-        part1_time, part1, part2_time, part2
-    )
+
+    let (op, val) = line.split_at(1);
+    Ok((op, parse_value(val)?))
 }
 
 // #### Compact solution ####
-#[derive(Copy, Clone)]
-enum CompactOpCode {
-    None,
+/// An instruction of the compact solution, with turns stored as quarter turns
+#[derive(Copy, Clone, Debug)]
+pub enum CompactOpCode {
     Heading((i32, i32)),
     Direction(i32, i32),
 }
@@ -45,11 +73,10 @@ enum CompactOpCode {
 const HEADINGS: &[(i32, i32)] = &[(0, 1), (1, 0), (0, -1), (-1, 0)];
 const ROTATIONS: &[(i32, i32)] = &[(-1, 1), (1, -1), (1, -1), (-1, 1)];
 
-fn parse_compact(op_val: (&str, &str)) -> CompactOpCode {
-    let (op, val) = op_val;
-    let val: i32 = val.parse().unwrap();
+pub fn parse_compact(line: &str) -> Result<CompactOpCode> {
+    let (op, val) = split_action(line)?;
 
-    match op {
+    Ok(match op {
         "N" => CompactOpCode::Heading((0, val)),
         "E" => CompactOpCode::Heading((val, 0)),
         "S" => CompactOpCode::Heading((0, -val)),
@@ -57,8 +84,8 @@ fn parse_compact(op_val: (&str, &str)) -> CompactOpCode {
         "L" => CompactOpCode::Direction(1, -(val / 90)),
         "R" => CompactOpCode::Direction(2, val / 90),
         "F" => CompactOpCode::Direction(0, val),
-        _ => CompactOpCode::None,
-    }
+        _ => return Err(Error::invalid("unknown action", op)),
+    })
 }
 
 fn swap(target: &mut (i32, i32), sign: &(i32, i32)) {
@@ -67,86 +94,73 @@ fn swap(target: &mut (i32, i32), sign: &(i32, i32)) {
     target.1 = temp * sign.1;
 }
 
-fn compact_solution_1(lines: &Vec<String>) -> i32 {
-    let (_, (part1_x, part1_y)) =
-        lines
-            .iter()
-            .map(|l| parse_compact(l.split_at(1)))
-            .fold((1i32, (0, 0)), |mut a, op| {
-                match op {
-                    CompactOpCode::None => (),
-                    CompactOpCode::Heading((x, y)) => {
-                        a.1 .0 += x;
-                        a.1 .1 += y;
+pub fn compact_solution_1(ops: &[CompactOpCode]) -> i32 {
+    let (_, (part1_x, part1_y)) = ops.iter().fold((1i32, (0, 0)), |mut a, op| {
+        match *op {
+            CompactOpCode::Heading((x, y)) => {
+                a.1 .0 += x;
+                a.1 .1 += y;
+            }
+            CompactOpCode::Direction(dir, val) => match dir {
+                0 => {
+                    a.1 .0 += HEADINGS[a.0 as usize].0 * val;
+                    a.1 .1 += HEADINGS[a.0 as usize].1 * val;
+                }
+                1 | 2 => {
+                    a.0 += val;
+                    if a.0 > 3 {
+                        a.0 -= 4;
+                    } else if a.0 < 0 {
+                        a.0 += 4;
                     }
-                    CompactOpCode::Direction(dir, val) => match dir {
-                        0 => {
-                            a.1 .0 += HEADINGS[a.0 as usize].0 * val;
-                            a.1 .1 += HEADINGS[a.0 as usize].1 * val;
-                        }
-                        1 | 2 => {
-                            a.0 += val;
-                            if a.0 > 3 {
-                                a.0 -= 4;
-                            } else if a.0 < 0 {
-                                a.0 += 4;
-                            }
-                        }
-                        _ => (),
-                    },
                 }
-                a
-            });
+                _ => (),
+            },
+        }
+        a
+    });
 
     part1_x.abs() + part1_y.abs()
 }
 
-fn compact_solution_2(lines: &Vec<String>) -> i32 {
-    let (_, (part2_x, part2_y)) =
-        lines
-            .iter()
-            .map(|l| parse_compact(l.split_at(1)))
-            .fold(((10, 1), (0, 0)), |mut a, op| {
-                match op {
-                    CompactOpCode::None => (),
-                    CompactOpCode::Heading((x, y)) => {
-                        a.0 .0 += x;
-                        a.0 .1 += y;
-                    }
-                    CompactOpCode::Direction(dir, val) => match dir {
-                        0 => {
-                            a.1 .0 += a.0 .0 * val;
-                            a.1 .1 += a.0 .1 * val;
-                        }
-                        1 | 2 if val.abs() == 2 => {
-                            a.0 .0 = -a.0 .0;
-                            a.0 .1 = -a.0 .1;
-                        }
-                        1 | 2 => swap(
-                            &mut a.0,
-                            &ROTATIONS[(dir * (val.abs() + 1) / 2 - 1) as usize],
-                        ),
-                        _ => (),
-                    },
+pub fn compact_solution_2(ops: &[CompactOpCode]) -> i32 {
+    let (_, (part2_x, part2_y)) = ops.iter().fold(((10, 1), (0, 0)), |mut a, op| {
+        match *op {
+            CompactOpCode::Heading((x, y)) => {
+                a.0 .0 += x;
+                a.0 .1 += y;
+            }
+            CompactOpCode::Direction(dir, val) => match dir {
+                0 => {
+                    a.1 .0 += a.0 .0 * val;
+                    a.1 .1 += a.0 .1 * val;
                 }
-                a
-            });
+                1 | 2 if val.abs() == 2 => {
+                    a.0 .0 = -a.0 .0;
+                    a.0 .1 = -a.0 .1;
+                }
+                1 | 2 => swap(
+                    &mut a.0,
+                    &ROTATIONS[(dir * (val.abs() + 1) / 2 - 1) as usize],
+                ),
+                _ => (),
+            },
+        }
+        a
+    });
 
     part2_x.abs() + part2_y.abs()
 }
 
 // #### Longer Solution but more clear ####
-fn long_solution(lines: &Vec<String>) -> (i32, i32) {
+pub fn long_solution(lines: &Vec<String>) -> Result<(i32, i32)> {
     // // Parsing
-    let ops: Vec<OpCode> = lines
-        .iter()
-        .map(|v| {
-            let (op, val) = v.split_at(1);
-            get_opcode(op, val)
-        })
-        .collect();
-
-    (solve_p1(&ops), solve_p2(&ops))
+    let ops: Vec<OpCode> = parse_lines(lines, |l| {
+        let (op, val) = split_action(l)?;
+        get_opcode(op, val)
+    })?;
+
+    Ok((solve_p1(&ops), solve_p2(&ops)))
 }
 
 fn solve_p2(ops: &Vec<OpCode>) -> i32 {
@@ -166,7 +180,6 @@ fn solve_p2(ops: &Vec<OpCode>) -> i32 {
 
 fn handle_op_p2(op: &OpCode, boat: &mut Boat, waypoint: &mut (i32, i32)) {
     match op {
-        OpCode::None => {}
         OpCode::Heading(h) => match h {
             Heading::N(val) => waypoint.1 += val,
             Heading::S(val) => waypoint.1 -= val,
@@ -233,7 +246,6 @@ fn solve_p1(ops: &Vec<OpCode>) -> i32 {
 
 fn handle_op_p1(op: &OpCode, boat: &mut Boat) {
     match op {
-        OpCode::None => {}
         OpCode::Heading(h) => match h {
             Heading::N(val) => {
                 boat.travel_distance.0 += val;
@@ -295,22 +307,21 @@ enum Direction {
 
 #[derive(Debug)]
 enum OpCode {
-    None,
     Heading(Heading),
     Direction(Direction),
 }
 
-fn get_opcode(op: &str, val: &str) -> OpCode {
-    match op {
-        "N" => OpCode::Heading(Heading::N(val.parse().unwrap())),
-        "E" => OpCode::Heading(Heading::E(val.parse().unwrap())),
-        "S" => OpCode::Heading(Heading::S(val.parse().unwrap())),
-        "W" => OpCode::Heading(Heading::W(val.parse().unwrap())),
-        "L" => OpCode::Direction(Direction::L(val.parse().unwrap())),
-        "R" => OpCode::Direction(Direction::R(val.parse().unwrap())),
-        "F" => OpCode::Direction(Direction::F(val.parse().unwrap())),
-        _ => OpCode::None,
-    }
+fn get_opcode(op: &str, val: i32) -> Result<OpCode> {
+    Ok(match op {
+        "N" => OpCode::Heading(Heading::N(val)),
+        "E" => OpCode::Heading(Heading::E(val)),
+        "S" => OpCode::Heading(Heading::S(val)),
+        "W" => OpCode::Heading(Heading::W(val)),
+        "L" => OpCode::Direction(Direction::L(val)),
+        "R" => OpCode::Direction(Direction::R(val)),
+        "F" => OpCode::Direction(Direction::F(val)),
+        _ => return Err(Error::invalid("unknown action", op)),
+    })
 }
 
 #[derive(Debug)]
@@ -0,0 +1,103 @@
+use std::{f32, i32};
+use std::rc::Rc;
+use gcd::*;
+
+use crate::error::{parse_value, Error, Result};
+use crate::solution::{Answer, Solution};
+use crate::validate::Problems;
+
+pub fn aoc_13(input: &str) -> Result<Solution> {
+    let lines: Vec<String> = input.lines().map(|l| l.to_string()).collect();
+    if lines.len() < 2 {
+        return Err(Error::new("expected a departure time and a line of buses"));
+    }
+
+    let part1 = solve_part1(&lines)?;
+    // let part2 = solve_part2(&lines);
+
+    Ok(Solution::new(part1, Answer::Unsolved))
+}
+
+/// A departure time followed by a line of bus IDs, where out of service buses are an x
+pub fn validate(input: &str) -> Vec<Error> {
+    let mut problems = Problems::new();
+    let lines: Vec<&str> = input.lines().collect();
+    if lines.len() != 2 {
+        problems.input(format!("expected 2 lines, found {}", lines.len()));
+    }
+
+    if let Some(line) = lines.first() {
+        problems.value::<u32>(1, line, line);
+    }
+    if let Some(line) = lines.get(1) {
+        let mut buses = 0;
+        for bus in line.split(',').filter(|bus| *bus != "x") {
+            match problems.value::<u32>(2, line, bus) {
+                Some(0) => problems.at(2, line, bus, "invalid bus"),
+                Some(_) => buses += 1,
+                None => (),
+            }
+        }
+        if buses == 0 {
+            problems.line(2, "no buses in service in", line);
+        }
+    }
+    problems.into_errors()
+}
+
+fn lcm(a: usize, b: usize) -> usize {
+    (a * b) / a.gcd(b)
+}
+
+fn solve_part2(lines: &Vec<String>) -> u32 {
+    let mut busses: Vec<(usize, usize)> = Vec::new();
+
+    for (i, c) in lines[1].split(',').enumerate() {
+        if c != "x" {
+            busses.push((c.parse::<usize>().unwrap(), i));
+        }
+    }
+
+    let first = busses.first().unwrap();
+    let last = busses.last().unwrap();
+
+    let step = first.0;
+    let mut timestamp = step;
+    
+    let mut l = 1;
+    let mut m = 1;
+    for (val, i) in busses {
+        l = lcm(l, val + i);
+        m *= val;
+    }
+
+    l = l % m;
+
+    println!("{} - {}", l, l % 3417);
+
+    // println!("{}", timestamp);
+
+    0
+}
+
+fn solve_part1(lines: &Vec<String>) -> Result<u32> {
+    let dtime: u32 = parse_value(&lines[0]).map_err(|err| err.at_line(1))?;
+    let busses: Vec<u32> = lines[1]
+        .split(',')
+        .filter(|v| *v != "x")
+        .map(parse_value)
+        .collect::<Result<_>>()
+        .map_err(|err| err.at_line(2))?;
+    if busses.contains(&0) {
+        return Err(Error::invalid("invalid bus", "0").at_line(2));
+    }
+
+    // The wait for a bus is whatever is left of its current loop when we arrive
+    let (bus, wait) = busses
+        .iter()
+        .map(|&bus| (bus, (bus - dtime % bus) % bus))
+        .min_by_key(|&(_, wait)| wait)
+        .ok_or_else(|| Error::new("no buses in service").at_line(2))?;
+
+    Ok(bus * wait)
+}
@@ -0,0 +1,278 @@
+use std::collections::{HashMap, HashSet};
+
+use crate::bench::{timed, Phase};
+use crate::error::{parse_lines, parse_value, split_once, Error, Result};
+use crate::generate::{self, Rng};
+use crate::metrics;
+use crate::solution::Solution;
+use crate::validate::Problems;
+
+pub type DefaultChip = Box<dyn Chip<MemoryStorage = HashMap<u64, u64>, Ops = Ops>>;
+
+const MASK_LEN: usize = 36;
+
+pub fn aoc_14(input: &str) -> Result<Solution> {
+    let ops = timed(Phase::Parse, || parse_lines(input.lines(), parse_line))?;
+
+    let part1 = timed(Phase::Part1, || {
+        let mut chipv1: DefaultChip = ChipV1::init();
+        chipv1.run(&ops);
+        chipv1.get_memory().count()
+    });
+
+    let part2 = timed(Phase::Part2, || {
+        let mut chipv2: DefaultChip = ChipV2::init();
+        chipv2.run(&ops);
+        chipv2.get_memory().count()
+    });
+
+    Ok(Solution::new(part1, part2))
+}
+
+/// Floating bits double the writes of part 2, real masks have no more than 9 of them
+const MAX_FLOATING: usize = 9;
+
+/// A random program of `size` masks, 100 by default, each followed by a few writes
+pub fn generate(rng: &mut Rng, size: Option<usize>) -> String {
+    let mut lines = Vec::new();
+    for _ in 0..size.unwrap_or(100) {
+        let floating = rng.below(MAX_FLOATING + 1);
+        let mut mask: Vec<char> = (0..MASK_LEN)
+            .map(|i| {
+                if i < floating {
+                    'X'
+                } else {
+                    *rng.pick(&['0', '1'])
+                }
+            })
+            .collect();
+        rng.shuffle(&mut mask);
+        lines.push(format!("mask = {}", mask.into_iter().collect::<String>()));
+
+        for _ in 0..rng.below(6) + 1 {
+            lines.push(format!(
+                "mem[{}] = {}",
+                rng.below(1 << 16),
+                rng.below(1 << 30)
+            ));
+        }
+    }
+    generate::lines(lines)
+}
+
+/// Each line either sets the mask, `mask = 0X1...`, or writes to memory, `mem[8] = 11`
+pub fn validate(input: &str) -> Vec<Error> {
+    let mut problems = Problems::new();
+    for (i, line) in input.lines().enumerate() {
+        let n = i + 1;
+        let (target, value) = match line.split_once(" = ") {
+            Some(split) => split,
+            None => {
+                problems.line(n, "expected 'target = value' in", line);
+                continue;
+            }
+        };
+
+        if target == "mask" {
+            if value.chars().count() != MASK_LEN {
+                problems.at(
+                    n,
+                    line,
+                    value,
+                    format!("expected a mask of {} bits, found", MASK_LEN),
+                );
+            }
+            problems.chars(n, line, value, |c| matches!(c, '0' | '1' | 'X'));
+        } else if let Some(address) = target
+            .strip_prefix("mem[")
+            .and_then(|t| t.strip_suffix(']'))
+        {
+            problems.value::<u64>(n, line, address);
+            problems.value::<u64>(n, line, value);
+        } else {
+            problems.at(n, line, target, "expected 'mask' or 'mem[address]', found");
+        }
+    }
+    problems.into_errors()
+}
+
+/// A line of the initialization program
+pub enum Ops {
+    Mask(String),
+    MemoryOp((u64, u64)),
+}
+
+pub fn parse_line(line: &str) -> Result<Ops> {
+    if line.starts_with("mask") {
+        let (_, mask) = split_once(line, "=")?;
+        let mask = mask.trim();
+        if mask.len() != MASK_LEN || !mask.chars().all(|c| matches!(c, '0' | '1' | 'X')) {
+            return Err(Error::invalid("invalid mask", mask));
+        }
+        Ok(Ops::Mask(mask.to_string()))
+    } else {
+        let (mem, value) = split_once(line, "=")?;
+        let value: u64 = parse_value(value.trim())?;
+        let (_, mem) = split_once(mem.trim(), "mem[")?;
+        let mem: u64 = parse_value(mem.trim_end_matches(']'))?;
+        Ok(Ops::MemoryOp((mem, value)))
+    }
+}
+
+pub trait Memory {
+    fn set(&mut self, loc: u64, val: u64);
+    fn count(&self) -> u64;
+    fn init() -> Box<Self>
+    where
+        Self: Sized;
+}
+
+impl Memory for HashMap<u64, u64> {
+    fn set(&mut self, loc: u64, val: u64) {
+        self.insert(loc, val);
+    }
+
+    fn count(&self) -> u64 {
+        self.values().sum()
+    }
+
+    fn init() -> Box<HashMap<u64, u64>> {
+        Box::new(HashMap::new())
+    }
+}
+
+/// A docking computer, which writes values to memory as instructed by the current mask
+pub trait Chip {
+    type MemoryStorage: Memory;
+    type Ops;
+
+    fn init() -> Box<Self>
+    where
+        Self: Sized;
+    fn get_memory(&self) -> &dyn Memory;
+    fn handle_memory_op(&mut self, loc: u64, val: u64, mask: &String);
+}
+
+impl dyn Chip<MemoryStorage = HashMap<u64, u64>, Ops = Ops> {
+    pub fn run(&mut self, ops: &[Ops]) {
+        let mut last_mask_op = "".to_string();
+        for op in ops {
+            match op {
+                Ops::Mask(mask) => {
+                    last_mask_op = mask.clone();
+                }
+                Ops::MemoryOp((loc, val)) => {
+                    self.handle_memory_op(*loc, *val, &last_mask_op);
+                }
+            }
+        }
+    }
+}
+
+// ### PART 1 ###
+
+/// Applies the mask to the values written
+pub struct ChipV1 {
+    memory: Box<dyn Memory>,
+}
+
+impl Chip for ChipV1 {
+    type MemoryStorage = HashMap<u64, u64>;
+    type Ops = Ops;
+
+    fn handle_memory_op(&mut self, loc: u64, mut val: u64, mask: &String) {
+        mask.chars()
+            .rev()
+            .enumerate()
+            .filter_map(|v| (v.1 != 'X').then_some(v))
+            .for_each(|v| {
+                let bit = v.1.to_digit(10).unwrap() as u64;
+                match bit {
+                    0 => val &= !(1 << v.0),
+                    1 => val |= 1 << v.0,
+                    _ => (),
+                }
+            });
+
+        self.memory.set(loc, val);
+    }
+
+    fn get_memory(&self) -> &dyn Memory {
+        self.memory.as_ref()
+    }
+
+    fn init() -> Box<Self> {
+        Box::new(ChipV1 {
+            memory: Self::MemoryStorage::init(),
+        })
+    }
+}
+
+// ### PART 2 ###
+
+/// Applies the mask to the addresses written to, where floating bits take every value
+pub struct ChipV2 {
+    memory: Box<dyn Memory>,
+}
+
+impl Chip for ChipV2 {
+    type MemoryStorage = HashMap<u64, u64>;
+    type Ops = Ops;
+
+    fn handle_memory_op(&mut self, loc: u64, val: u64, mask: &String) {
+        let mut flag = loc;
+        for (i, c) in mask.chars().rev().enumerate() {
+            if c != 'X' {
+                let bit: u64 = c.to_digit(10).unwrap() as u64;
+                flag |= (1 << i) * bit;
+            }
+        }
+
+        // Shouldnt use/recreate hashset here because its slow
+        let mut floating = HashSet::new();
+        floating.insert(flag);
+        generate_memory_addresses(mask, 0, flag, &mut floating);
+
+        metrics::count("addresses_written", floating.len() as u64);
+        for addr in floating {
+            self.memory.set(addr, val);
+        }
+    }
+
+    fn get_memory(&self) -> &dyn Memory {
+        self.memory.as_ref()
+    }
+
+    fn init() -> Box<Self> {
+        Box::new(ChipV2 {
+            memory: Self::MemoryStorage::init(),
+        })
+    }
+}
+
+fn generate_memory_addresses(mask: &str, index: usize, flag: u64, set: &mut HashSet<u64>) {
+    for (i, c) in mask[index..].chars().rev().enumerate() { // Rev'ing here is slow
+        if c == 'X' {
+            let variation = flag ^ (1 << i);
+
+            if set.insert(variation) {
+                generate_memory_addresses(mask, index + 1, variation, set);
+            }
+            if set.insert(flag) {
+                generate_memory_addresses(mask, index + 1, flag, set);
+            }
+        }
+    }
+}
+
+fn print_mask(mask: u64) {
+    for i in (0..36u64).rev() {
+        let set = mask & (1 << i);
+        if set == 0 {
+            print!("{}", 0);
+        } else {
+            print!("{}", 1);
+        }
+    }
+    println!(" {}", mask);
+}
@@ -0,0 +1,82 @@
+use std::usize;
+
+use crate::bench::{timed, Phase};
+use crate::error::{parse_value, Error, Result};
+use crate::solution::{Answer, Solution};
+use crate::validate::Problems;
+
+const PART1_TURNS: usize = 2020;
+
+pub fn aoc_15(input: &str) -> Result<Solution> {
+    let nums = timed(Phase::Parse, || {
+        let line = input
+            .lines()
+            .next()
+            .ok_or_else(|| Error::new("empty input"))?;
+        line.split(',')
+            .map(parse_value)
+            .collect::<Result<Vec<usize>>>()
+            .map_err(|err| err.at_line(1))
+    })?;
+
+    let part1 = timed(Phase::Part1, || solve_part(&nums, PART1_TURNS));
+    let part2 = Answer::Unsolved;
+    // let part2 = timed(Phase::Part2, || solve_part(&nums, 30_000_000));
+
+    Ok(Solution::new(part1, part2))
+}
+
+/// A single line of starting numbers, each below the number of turns played
+pub fn validate(input: &str) -> Vec<Error> {
+    let mut problems = Problems::new();
+    let mut lines = input.lines();
+    let line = match lines.next() {
+        Some(line) if !line.is_empty() => line,
+        _ => {
+            problems.input("empty input");
+            return problems.into_errors();
+        }
+    };
+    if lines.next().is_some() {
+        problems.input("expected a single line of starting numbers");
+    }
+
+    for num in line.split(',') {
+        if let Some(val) = problems.value::<usize>(1, line, num) {
+            if val >= PART1_TURNS {
+                problems.at(
+                    1,
+                    line,
+                    num,
+                    format!("starting numbers must be below {}, found", PART1_TURNS),
+                );
+            }
+        }
+    }
+    problems.into_errors()
+}
+
+fn solve_part(nums: &Vec<usize>, stop_at: usize) -> usize {
+    let mut spoken = vec![(0usize, 0usize); stop_at];
+    for (i, num) in nums.iter().enumerate() {
+        spoken[*num] = (i + 1, 0);
+    }
+
+
+    let mut last_number = *nums.last().unwrap();
+    for i in nums.len() + 1..=stop_at {
+        let current = spoken[last_number];
+        
+        let diff = match current.1 {
+            0 => 0,
+            _ => current.0 - current.1,
+        };
+        
+        spoken[diff].1 = spoken[diff].0;
+        spoken[diff].0 = i;
+        
+        last_number = diff;
+    }
+
+    last_number
+}
@@ -0,0 +1,203 @@
+use std::{collections::HashSet, usize};
+
+use crate::error::{parse_lines, parse_value, split_once, Error, Result};
+use crate::solution::Solution;
+use crate::validate::Problems;
+
+#[derive(Default, Debug)]
+struct Either<T> {
+    a: T,
+    b: T,
+}
+
+impl Either<(u32, u32)> {
+    pub fn pick(&self, num: u32) -> Option<(u32, u32)> {
+        if num >= self.a.0 && num <= self.a.1 {
+            Some(self.a)
+        } else if num >= self.b.0 && num <= self.b.1 {
+            Some(self.b)
+        } else {
+            None
+        }
+    }
+    pub fn parse(input: &str) -> Result<Either<(u32, u32)>> {
+        let (a, b) = split_once(input, " or ")?;
+
+        let (a0, a1) = split_once(a, "-")?;
+        let (b0, b1) = split_once(b, "-")?;
+
+        Ok(Either {
+            a: (parse_value(a0)?, parse_value(a1)?),
+            b: (parse_value(b0)?, parse_value(b1)?),
+        })
+    }
+}
+
+fn parse_ticket(input: &str) -> Result<Vec<u32>> {
+    input.split(',').map(parse_value).collect()
+}
+
+/// Checks that the line at `index` is the expected section header
+fn expect_header(lines: &[&str], index: usize, header: &str) -> Result<()> {
+    match lines.get(index) {
+        Some(line) if *line == header => Ok(()),
+        Some(line) => {
+            Err(Error::invalid(format!("expected '{}', found", header), line).at_line(index + 1))
+        }
+        None => Err(Error::new(format!("missing '{}' section", header))),
+    }
+}
+
+pub fn aoc_16(input: &str) -> Result<Solution> {
+    let lines: Vec<&str> = input.lines().collect();
+
+    // Rules, a blank line, then "your ticket:" and "nearby tickets:" sections
+    let rule_count = lines
+        .iter()
+        .position(|l| l.is_empty())
+        .ok_or_else(|| Error::new("missing blank line after the rules"))?;
+    let rules = parse_lines(&lines[..rule_count], |l| {
+        let (_, ranges) = split_once(l, ": ")?;
+        Either::parse(ranges)
+    })?;
+
+    let mine = rule_count + 1;
+    expect_header(&lines, mine, "your ticket:")?;
+    let my_ticket = lines
+        .get(mine + 1)
+        .ok_or_else(|| Error::new("missing your ticket"))
+        .and_then(|l| parse_ticket(l))
+        .map_err(|err| err.at_line(mine + 2))?;
+
+    let nearby = mine + 3;
+    expect_header(&lines, nearby, "nearby tickets:")?;
+    let other_tickets = parse_lines(&lines[nearby + 1..], parse_ticket).map_err(|mut err| {
+        err.line = err.line.map(|line| line + nearby + 1);
+        err
+    })?;
+
+    let part1 = part_1(&other_tickets, &rules);
+
+    // let part2 = part_2(other_tickets, &field_ranges, &my_ticket);
+    let part2 = part_2(other_tickets, my_ticket, rules);
+
+    Ok(Solution::new(part1, part2))
+}
+
+/// Rules such as `class: 1-3 or 5-7`, then a blank line, `your ticket:` with a ticket,
+/// another blank line and `nearby tickets:` with the rest. Every ticket has a field per rule.
+pub fn validate(input: &str) -> Vec<Error> {
+    let mut problems = Problems::new();
+    let lines: Vec<&str> = input.lines().collect();
+
+    let rule_count = lines
+        .iter()
+        .position(|l| l.is_empty())
+        .unwrap_or(lines.len());
+    for (i, line) in lines[..rule_count].iter().enumerate() {
+        let ranges = match line.split_once(": ") {
+            Some((_, ranges)) => ranges,
+            None => {
+                problems.line(i + 1, "expected 'name: ranges' in", line);
+                continue;
+            }
+        };
+        match ranges.split_once(" or ") {
+            Some((a, b)) => {
+                for range in [a, b].iter() {
+                    match range.split_once('-') {
+                        Some((min, max)) => {
+                            problems.value::<u32>(i + 1, line, min);
+                            problems.value::<u32>(i + 1, line, max);
+                        }
+                        None => problems.at(i + 1, line, range, "expected 'min-max', found"),
+                    }
+                }
+            }
+            None => problems.at(i + 1, line, ranges, "expected 'a-b or c-d', found"),
+        }
+    }
+
+    let sections = [
+        (rule_count + 1, "your ticket:"),
+        (rule_count + 4, "nearby tickets:"),
+    ];
+    for (i, &(header, name)) in sections.iter().enumerate() {
+        match lines.get(header) {
+            Some(line) if *line == name => (),
+            Some(line) => {
+                problems.line(header + 1, format!("expected '{}', found", name), line);
+                continue;
+            }
+            None => {
+                problems.input(format!("missing '{}' section", name));
+                continue;
+            }
+        }
+
+        // Your ticket is a single line followed by a blank one, nearby tickets run until the end
+        let end = if i == 0 { header + 2 } else { lines.len() };
+        for (index, line) in lines.iter().enumerate().take(end).skip(header + 1) {
+            let fields = line.split(',').count();
+            if fields != rule_count {
+                problems.line(
+                    index + 1,
+                    format!("expected {} fields, found {} in", rule_count, fields),
+                    line,
+                );
+            }
+            for field in line.split(',') {
+                problems.value::<u32>(index + 1, line, field);
+            }
+        }
+    }
+    problems.into_errors()
+}
+
+fn part_1(tickets: &Vec<Vec<u32>>, rules: &Vec<Either<(u32, u32)>>) -> u32 {
+    let mut invalid_sum = 0;
+
+    for ticket in tickets {
+        let valid = ticket
+            .iter()
+            .find(|v| !rules.iter().any(|r| r.pick(**v).is_some()));
+
+        if let Some(invalid) = valid {
+            invalid_sum += *invalid;
+        }
+    }
+
+    invalid_sum
+}
+
+fn part_2(tickets: Vec<Vec<u32>>, my_ticket: Vec<u32>, rules: Vec<Either<(u32, u32)>>) -> usize {
+    let mut tickets: Vec<_> = tickets
+        .iter()
+        .filter(|t| {
+            t.iter()
+                .all(|f| rules.iter().any(|r| r.pick(*f).is_some()))
+        })
+        .collect();
+    tickets.push(&my_ticket);
+
+    let mut seen = HashSet::new();
+    let indices = rules.iter().map(|rule| {
+        for j in 0..my_ticket.len() {
+            if seen.contains(&j) {
+                continue;
+            }
+            if tickets.iter().all(|v| rule.pick(v[j]).is_some()) {
+                seen.insert(j);
+                return j;
+            }
+        }
+        0
+    });
+
+    let prod = indices
+        .take(6)
+        .map(|i| my_ticket[i])
+        .fold(1usize, |acc, v| acc * v as usize);
+
+    prod
+}
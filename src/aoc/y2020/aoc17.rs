@@ -2,10 +2,10 @@ use std::{
     collections::{HashMap, HashSet},
     usize,
 };
-use std::{
-    fs::File,
-    io::{BufRead, BufReader},
-};
+
+use crate::error::{Error, Result};
+use crate::solution::{Answer, Solution};
+use crate::validate;
 
 enum State {
     Active,
@@ -25,8 +25,8 @@ struct Node {
     neighbours: Vec<Vec3>,
 }
 
-pub fn aoc_17(reader: BufReader<File>) -> String {
-    let lines: Vec<String> = reader.lines().map(|l| l.unwrap()).collect();
+pub fn aoc_17(input: &str) -> Result<Solution> {
+    let lines: Vec<String> = input.lines().map(|l| l.to_string()).collect();
 
     let mut grid = HashMap::new();
 
@@ -51,19 +51,24 @@ pub fn aoc_17(reader: BufReader<File>) -> String {
         }
     }
 
+    let positions: Vec<Vec3> = grid.keys().copied().collect();
     for _ in 0..6 {
-        for (p, n) in &mut grid {
+        for p in &positions {
             tick_node(&mut grid, p);
         }
     }
 
-    format!("")
+    Ok(Solution::new(Answer::Unsolved, Answer::Unsolved))
 }
 
-fn tick_node(grid: &mut HashMap<Vec3, Node>, pos: &Vec3) {
-    let mut node = grid.get_mut(pos).unwrap();
+/// The initial slice is a rectangle of active and inactive cubes
+pub fn validate(input: &str) -> Vec<Error> {
+    validate::grid(input, ".#")
+}
 
-    if node.neighbours.len() == 0 {
+fn tick_node(grid: &mut HashMap<Vec3, Node>, pos: &Vec3) {
+    if grid[pos].neighbours.len() == 0 {
+        let mut neighbours = Vec::new();
         for x in -1..1 {
             for y in -1..1 {
                 for z in -1..1 {
@@ -73,11 +78,12 @@ fn tick_node(grid: &mut HashMap<Vec3, Node>, pos: &Vec3) {
 
                     let pos = Vec3{x: x, y: y, z: z};
                     if grid.contains_key(&pos) {
-                        node.neighbours.push(pos);
+                        neighbours.push(pos);
                     }
                 }
             }
         }
+        grid.get_mut(pos).unwrap().neighbours = neighbours;
 
     }
 
@@ -0,0 +1,248 @@
+use std::ops::RangeInclusive;
+
+use crate::bench::{timed, Phase};
+use crate::error::{parse_lines, parse_value, split_once, Error, Result};
+use crate::output::Table;
+use crate::solution::Solution;
+use crate::validate::Problems;
+
+pub fn aoc_2(input: &str) -> Result<Solution> {
+    let entries = timed(Phase::Parse, || {
+        parse_lines(input.lines(), PasswordEntry::parse)
+    })?;
+
+    let part1 = timed(Phase::Part1, || count_allowed::<CountRange>(&entries))?;
+    let part2 = timed(Phase::Part2, || count_allowed::<PositionXor>(&entries))?;
+
+    Ok(Solution::new(part1, part2))
+}
+
+/// Each line is a policy and a password such as `1-3 a: abcde`
+pub fn validate(input: &str) -> Vec<Error> {
+    let mut problems = Problems::new();
+    for (i, line) in input.lines().enumerate() {
+        let n = i + 1;
+        let (policy, password) = match line.split_once(": ") {
+            Some(split) => split,
+            None => {
+                problems.line(n, "expected 'policy: password' in", line);
+                continue;
+            }
+        };
+        let (range, letter) = match policy.split_once(' ') {
+            Some(split) => split,
+            None => {
+                problems.at(n, line, policy, "expected 'min-max letter' in");
+                continue;
+            }
+        };
+
+        match range.split_once('-') {
+            Some((min_text, max_text)) => {
+                let min = problems.value::<usize>(n, line, min_text);
+                let max = problems.value::<usize>(n, line, max_text);
+                if let (Some(min), Some(max)) = (min, max) {
+                    if min > max {
+                        problems.at(n, line, range, "minimum is above the maximum in");
+                    }
+                }
+                // Part 2 reads the numbers as 1-based positions
+                if min == Some(0) {
+                    problems.at(n, line, min_text, "positions start at 1, found");
+                }
+            }
+            None => problems.at(n, line, range, "expected 'min-max' in"),
+        }
+        if letter.chars().count() != 1 {
+            problems.at(n, line, letter, "expected a single letter, found");
+        }
+        problems.chars(n, line, password, |c| c.is_ascii_lowercase());
+    }
+    problems.into_errors()
+}
+
+/// The two numbers and the letter of a policy, before a policy gives them a meaning
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Rule {
+    pub first: usize,
+    pub second: usize,
+    pub letter: char,
+}
+
+/// A line of the password database, such as `1-3 a: abcde`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PasswordEntry {
+    /// The rule before the password, which each part reads as its own `Policy`
+    pub policy: Rule,
+    pub password: String,
+}
+
+impl PasswordEntry {
+    pub fn parse(line: &str) -> Result<PasswordEntry> {
+        let (policy, password) = split_once(line, ":")?;
+        let (numbers, letter) = split_once(policy.trim(), " ")?;
+        let (first, second) = split_once(numbers.trim(), "-")?;
+        let letter = letter
+            .trim()
+            .chars()
+            .next()
+            .ok_or_else(|| Error::invalid("missing policy letter in", line))?;
+
+        Ok(PasswordEntry {
+            policy: Rule {
+                first: parse_value(first.trim())?,
+                second: parse_value(second.trim())?,
+                letter,
+            },
+            password: password.trim().to_string(),
+        })
+    }
+}
+
+/// A way of reading the rule of an entry, deciding which passwords it allows
+pub trait Policy: Sized {
+    /// Fails if the rule makes no sense under this policy
+    fn from_rule(rule: &Rule) -> Result<Self>;
+
+    fn allows(&self, password: &str) -> bool;
+
+    /// Why the password is allowed or not, e.g. `'a' appears 5 times, allowed 1-3`
+    fn explain(&self, password: &str) -> String;
+}
+
+/// The letter must appear a number of times within the range, the policy of part 1
+pub struct CountRange {
+    pub range: RangeInclusive<usize>,
+    pub letter: char,
+}
+
+impl Policy for CountRange {
+    fn from_rule(rule: &Rule) -> Result<CountRange> {
+        Ok(CountRange {
+            range: rule.first..=rule.second,
+            letter: rule.letter,
+        })
+    }
+
+    fn allows(&self, password: &str) -> bool {
+        self.range.contains(&self.count(password))
+    }
+
+    fn explain(&self, password: &str) -> String {
+        format!(
+            "'{}' appears {} times, allowed {}-{}",
+            self.letter,
+            self.count(password),
+            self.range.start(),
+            self.range.end()
+        )
+    }
+}
+
+impl CountRange {
+    fn count(&self, password: &str) -> usize {
+        password.chars().filter(|&c| c == self.letter).count()
+    }
+}
+
+/// The letter must be at exactly one of two 1-based positions, the policy of part 2
+pub struct PositionXor {
+    /// 0-based indices of the positions
+    pub positions: [usize; 2],
+    pub letter: char,
+}
+
+impl Policy for PositionXor {
+    fn from_rule(rule: &Rule) -> Result<PositionXor> {
+        let index = |position: usize| {
+            position
+                .checked_sub(1)
+                .ok_or_else(|| Error::invalid("positions start at 1, found", &position.to_string()))
+        };
+        Ok(PositionXor {
+            positions: [index(rule.first)?, index(rule.second)?],
+            letter: rule.letter,
+        })
+    }
+
+    fn allows(&self, password: &str) -> bool {
+        let [first, second] = self
+            .positions
+            .map(|i| password.chars().nth(i) == Some(self.letter));
+        first != second
+    }
+
+    fn explain(&self, password: &str) -> String {
+        let [first, second] = self.positions.map(|i| i + 1);
+        match self
+            .positions
+            .map(|i| password.chars().nth(i) == Some(self.letter))
+        {
+            [true, true] => format!(
+                "positions {} and {} both contain '{}'",
+                first, second, self.letter
+            ),
+            [true, false] => format!("only position {} contains '{}'", first, self.letter),
+            [false, true] => format!("only position {} contains '{}'", second, self.letter),
+            [false, false] => format!(
+                "neither position {} nor {} contains '{}'",
+                first, second, self.letter
+            ),
+        }
+    }
+}
+
+/// How many entries have a password their rule allows, when read as policy `P`
+pub fn count_allowed<P: Policy>(entries: &[PasswordEntry]) -> Result<usize> {
+    let mut allowed = 0;
+    for (i, entry) in entries.iter().enumerate() {
+        let policy = P::from_rule(&entry.policy).map_err(|err| err.at_line(i + 1))?;
+        if policy.allows(&entry.password) {
+            allowed += 1;
+        }
+    }
+    Ok(allowed)
+}
+
+/// Whether each line passes the policy of each part and why, lines that cannot be read included
+pub fn report(input: &str) -> Result<Table> {
+    let mut table = Table::new(vec![
+        "line",
+        "entry",
+        "part1",
+        "part1_reason",
+        "part2",
+        "part2_reason",
+    ]);
+    for (i, line) in input.lines().enumerate() {
+        let mut row = vec![(i + 1).to_string(), line.to_string()];
+        match PasswordEntry::parse(line) {
+            Ok(entry) => {
+                row.extend(audit::<CountRange>(&entry));
+                row.extend(audit::<PositionXor>(&entry));
+            }
+            Err(err) => {
+                for _ in 0..2 {
+                    row.extend(vec!["error".to_string(), err.to_string()]);
+                }
+            }
+        }
+        table.push(row);
+    }
+    Ok(table)
+}
+
+/// The verdict of policy `P` on an entry and the reason for it
+fn audit<P: Policy>(entry: &PasswordEntry) -> Vec<String> {
+    match P::from_rule(&entry.policy) {
+        Ok(policy) => {
+            let verdict = if policy.allows(&entry.password) {
+                "pass"
+            } else {
+                "fail"
+            };
+            vec![verdict.to_string(), policy.explain(&entry.password)]
+        }
+        Err(err) => vec!["error".to_string(), err.to_string()],
+    }
+}
@@ -0,0 +1,92 @@
+use crate::bench::{timed, Phase};
+use crate::error::{parse_value, Error, Result};
+use crate::grid::Grid2D;
+use crate::solution::Solution;
+use crate::validate;
+
+const TREE_SQUARE: u8 = b'#';
+
+/// How far a slope goes right for every step down
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Slope {
+    pub right: usize,
+    pub down: usize,
+}
+
+impl Slope {
+    pub const fn new(right: usize, down: usize) -> Slope {
+        Slope { right, down }
+    }
+
+    /// Reads slopes such as `1,1:3,1:1,2`, each a step right and a step down
+    pub fn parse_list(text: &str) -> Result<Vec<Slope>> {
+        text.split(':')
+            .map(|slope| {
+                let (right, down) = slope
+                    .split_once(',')
+                    .ok_or_else(|| Error::invalid("expected 'right,down' in", slope))?;
+                Ok(Slope::new(
+                    parse_value(right.trim())?,
+                    parse_value(down.trim())?,
+                ))
+            })
+            .collect()
+    }
+}
+
+/// The slope of part 1
+pub const PART1_SLOPE: Slope = Slope::new(3, 1);
+
+/// The slopes whose trees are multiplied together in part 2
+pub const PART2_SLOPES: &[Slope] = &[
+    Slope::new(1, 1),
+    Slope::new(3, 1),
+    Slope::new(5, 1),
+    Slope::new(7, 1),
+    Slope::new(1, 2),
+];
+
+pub fn aoc_3(input: &str) -> Result<Solution> {
+    solve(input, PART2_SLOPES)
+}
+
+/// Solves the day with part 2 multiplying the trees of `slopes` rather than those of the puzzle
+pub fn solve(input: &str, slopes: &[Slope]) -> Result<Solution> {
+    let grid = timed(Phase::Parse, || Grid2D::parse(input))?;
+
+    let part1 = timed(Phase::Part1, || count_trees(&grid, PART1_SLOPE))?;
+    let part2 = timed(Phase::Part2, || product_of_trees(&grid, slopes))?;
+
+    Ok(Solution::new(part1, part2))
+}
+
+/// The map is a rectangle of open squares and trees
+pub fn validate(input: &str) -> Vec<Error> {
+    validate::grid(input, ".#")
+}
+
+/// The trees hit going down `slope` from the top left, the map repeating to the right
+pub fn count_trees(grid: &Grid2D, slope: Slope) -> Result<usize> {
+    if slope.down == 0 {
+        return Err(Error::new("a slope has to go down"));
+    }
+
+    Ok((0..grid.height())
+        .step_by(slope.down)
+        .enumerate()
+        .filter(|&(step, y)| grid.get_wrapped(step * slope.right, y) == Some(TREE_SQUARE))
+        .count())
+}
+
+/// The trees hit on each of `slopes`, multiplied together.
+/// Fails without slopes rather than answering the empty product of 1.
+pub fn product_of_trees(grid: &Grid2D, slopes: &[Slope]) -> Result<usize> {
+    if slopes.is_empty() {
+        return Err(Error::new("no slopes given"));
+    }
+
+    slopes
+        .iter()
+        .map(|&slope| count_trees(grid, slope))
+        .product()
+}
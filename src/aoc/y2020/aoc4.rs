@@ -1,13 +1,15 @@
-use std::fs::File;
-use std::io::prelude::*;
-use std::{io::BufReader, ops::RangeInclusive};
+use std::ops::RangeInclusive;
 
-pub fn aoc_4(reader: BufReader<File>) -> String {
+use crate::error::{Error, Result};
+use crate::solution::{Answer, Solution};
+use crate::validate::Problems;
+
+pub fn aoc_4(input: &str) -> Result<Solution> {
     let mut unparsed_records = Vec::new();
     let mut unparsed_record = "".to_string();
     let mut count = 0;
-    for line in reader.lines() {
-        let buf = line.unwrap();
+    for line in input.lines() {
+        let buf = line.to_string();
 
         if buf.is_empty() {
             // println!("{}", unparsed_record);
@@ -40,7 +42,36 @@ pub fn aoc_4(reader: BufReader<File>) -> String {
         }
     }
 
-    format!("{}", valid_passports)
+    Ok(Solution::new(Answer::Unsolved, valid_passports))
+}
+
+const FIELDS: &[&str] = &["byr", "iyr", "eyr", "hgt", "hcl", "ecl", "pid", "cid"];
+
+/// Passports are separated by blank lines and made of `key:value` fields.
+/// Only the syntax is checked, as telling valid field values apart is the puzzle itself.
+pub fn validate(input: &str) -> Vec<Error> {
+    let mut problems = Problems::new();
+    let mut seen = Vec::new();
+    for (i, line) in input.lines().enumerate() {
+        if line.trim().is_empty() {
+            seen.clear();
+            continue;
+        }
+
+        for field in line.split_whitespace() {
+            match field.split_once(':') {
+                Some((key, _)) if !FIELDS.contains(&key) => {
+                    problems.at(i + 1, line, key, "unknown field")
+                }
+                Some((key, _)) if seen.contains(&key) => {
+                    problems.at(i + 1, line, key, "field is repeated in the passport")
+                }
+                Some((key, _)) => seen.push(key),
+                None => problems.at(i + 1, line, field, "expected 'key:value', found"),
+            }
+        }
+    }
+    problems.into_errors()
 }
 
 #[derive(Default, Debug)]
@@ -163,13 +194,7 @@ fn parse_hgt(input: &str) -> Option<(u64, String)> {
 }
 
 fn parse_u64(input: &str) -> u64 {
-    match input.parse::<u64>() {
-        Ok(val) => val,
-        Err(_) => {
-            println!("--- {}", input);
-            0
-        }
-    }
+    input.parse::<u64>().unwrap_or(0)
 }
 
 fn parse_range(input: &str, range: RangeInclusive<u64>) -> u64 {
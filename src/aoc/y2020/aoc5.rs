@@ -0,0 +1,69 @@
+use std::str::Chars;
+
+use crate::bench::{timed, Phase};
+use crate::error::{parse_lines, Error, Result};
+use crate::solution::Solution;
+use crate::validate::Problems;
+
+pub fn aoc_5(input: &str) -> Result<Solution> {
+    let seat_ids = timed(Phase::Parse, || -> Result<Vec<u32>> {
+        let mut seat_ids = parse_lines(input.lines(), seat_id)?;
+
+        seat_ids.sort();
+        Ok(seat_ids)
+    })?;
+
+    let max = timed(Phase::Part1, || seat_ids.last().copied())
+        .ok_or_else(|| Error::new("no seats in input"))?;
+    let my_seat = timed(Phase::Part2, || {
+        seat_ids
+            .windows(2)
+            .find(|c| c[1] - c[0] != 1)
+            .map(|c| c[0] + 1)
+    })
+    .ok_or_else(|| Error::new("no free seat between two taken ones"))?;
+
+    Ok(Solution::new(max, my_seat))
+}
+
+/// Each line is a boarding pass of 7 row and 3 column characters
+pub fn validate(input: &str) -> Vec<Error> {
+    let mut problems = Problems::new();
+    for (i, line) in input.lines().enumerate() {
+        if line.len() != 10 || !line.is_ascii() {
+            problems.line(i + 1, "expected 10 characters in", line);
+            continue;
+        }
+
+        let (row, col) = line.split_at(7);
+        problems.chars(i + 1, line, row, |c| c == 'F' || c == 'B');
+        problems.chars(i + 1, line, col, |c| c == 'L' || c == 'R');
+    }
+    problems.into_errors()
+}
+
+/// The seat ID of a boarding pass such as `FBFBBFFRLR`, which is its row * 8 + column
+pub fn seat_id(pass: &str) -> Result<u32> {
+    if pass.len() != 10 || !pass.is_char_boundary(7) {
+        return Err(Error::invalid("expected a 10 character seat", pass));
+    }
+    let (row, col) = pass.split_at(7);
+    let row = bsp_search(row.chars(), (0, 127));
+    let col = bsp_search(col.chars(), (0, 7));
+    Ok(row * 8 + col)
+}
+
+fn bsp_search(mut seq: Chars, mut range: (u32, u32)) -> u32 {
+    match seq.nth(0) {
+        Some(c) => {
+            let mid = (range.0 + range.1) / 2;
+            match c {
+                'F' | 'L' => range.1 = mid,
+                'B' | 'R' => range.0 = mid,
+                _ => (),
+            }
+            bsp_search(seq, range)
+        }
+        None => range.1,
+    }
+}
@@ -0,0 +1,40 @@
+use crate::error::{Error, Result};
+use crate::solution::{Answer, Solution};
+use crate::validate::Problems;
+
+pub fn aoc_6(input: &str) -> Result<Solution> {
+    let lines: Vec<_> = input.lines().map(|l| l.to_string()).collect();
+
+    let sum: u32 = lines
+        .split(|l| l.is_empty())
+        .map(|g| {
+            let mut group_count: [u32; 255] = [0; 255];
+            g.iter()
+                .map(|s| s.bytes())
+                .for_each(|bs| bs.for_each(|b| group_count[b as usize] += 1));
+            (g.len() as u32, group_count)
+        })
+        .map(|d| d.1.iter().filter(|v| **v == d.0).count() as u32)
+        .sum();
+
+    Ok(Solution::new(Answer::Unsolved, sum))
+}
+
+/// Groups are separated by blank lines, with a line of distinct answers per person
+pub fn validate(input: &str) -> Vec<Error> {
+    let mut problems = Problems::new();
+    for (i, line) in input.lines().enumerate() {
+        problems.chars(i + 1, line, line, |c| c.is_ascii_lowercase());
+        for (j, c) in line.char_indices() {
+            if line[..j].contains(c) {
+                problems.at(
+                    i + 1,
+                    line,
+                    &line[j..j + c.len_utf8()],
+                    "answer is repeated",
+                );
+            }
+        }
+    }
+    problems.into_errors()
+}
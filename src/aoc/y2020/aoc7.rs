@@ -0,0 +1,282 @@
+use parking_lot::RwLock;
+use std::collections::HashMap;
+use std::collections::VecDeque;
+use std::{collections::HashSet, sync::Arc, thread::JoinHandle};
+
+use crate::bench::{timed, Phase};
+use crate::error::{parse_lines, parse_value, split_once, Error, Result};
+use crate::generate::{self, Rng};
+use crate::metrics;
+use crate::solution::Solution;
+use crate::validate::Problems;
+
+const SHINY_GOLD: &str = "shiny gold";
+
+const ADJECTIVES: &[&str] = &[
+    "bright", "clear", "dark", "dim", "dotted", "drab", "dull", "faded", "light", "mirrored",
+    "muted", "pale", "plaid", "posh", "shiny", "striped", "vibrant", "wavy",
+];
+const COLORS: &[&str] = &[
+    "aqua", "beige", "black", "blue", "bronze", "brown", "chartreuse", "coral", "crimson", "cyan",
+    "fuchsia", "gold", "gray", "green", "indigo", "lavender", "lime", "magenta", "maroon", "olive",
+    "orange", "plum", "purple", "red", "salmon", "silver", "tan", "teal", "tomato", "turquoise",
+    "violet", "white", "yellow",
+];
+
+#[derive(Debug, Default, Clone)]
+struct Bag {
+    contents: HashMap<String, u32>,
+}
+
+#[derive(Default, Debug, Clone)]
+struct Bags {
+    contents: HashMap<String, Bag>,
+}
+
+fn digest_content(line: &str) -> Result<(String, u32)> {
+    let split = line
+        .rmatch_indices(" bag")
+        .next()
+        .ok_or_else(|| Error::invalid("expected ' bag' in", line))?
+        .0;
+    let inner = line.split_at(split).0;
+
+    let (count, color) = split_once(inner.trim(), " ")?;
+    let color = color.trim_start().to_owned();
+    let count: u32 = parse_value(count)?;
+
+    Ok((color, count))
+}
+
+fn parse_contents(reader: &Vec<String>) -> Result<Bags> {
+    let mut bags: Bags = Default::default();
+    parse_lines(reader, |l| {
+        let (color, content) = split_once(l, " bags contain ")?;
+
+        let mut bag = Bag::default();
+        if !content.contains("no other bags") {
+            for item in content.split(", ") {
+                let (color, count) = digest_content(item)?;
+                bag.contents.insert(color, count);
+            }
+        }
+        bags.contents.insert(color.trim().to_owned(), bag);
+        Ok(())
+    })?;
+    Ok(bags)
+}
+
+pub fn aoc_7(input: &str) -> Result<Solution> {
+    let lines: Vec<String> = input.lines().map(|l| l.to_string()).collect();
+
+    let bags = timed(Phase::Parse, || parse_contents(&lines))?;
+    let part1 = timed(Phase::Part1, || part_1(&bags));
+    let part2 = timed(Phase::Part2, || {
+        let tree = BagTree::construct(&bags, SHINY_GOLD.to_string());
+        tree.part_2()
+    });
+
+    Ok(Solution::new(part1, part2))
+}
+
+/// Bags only contain bags of a deeper level, which keeps the rules free of cycles
+/// and the number of bags inside shiny gold within a u32
+const LEVELS: usize = 7;
+const SHINY_GOLD_LEVEL: usize = 2;
+
+/// Random rules for `size` bags, 594 by default and at most that many as there are no more colors
+pub fn generate(rng: &mut Rng, size: Option<usize>) -> String {
+    let mut colors: Vec<String> = ADJECTIVES
+        .iter()
+        .flat_map(|adj| COLORS.iter().map(move |color| format!("{} {}", adj, color)))
+        .filter(|color| color != SHINY_GOLD)
+        .collect();
+    rng.shuffle(&mut colors);
+    let max = ADJECTIVES.len() * COLORS.len();
+    colors.truncate(size.unwrap_or(max).clamp(1, max) - 1);
+    colors.push(SHINY_GOLD.to_string());
+
+    let mut bags: Vec<(usize, &String)> = colors
+        .iter()
+        .map(|color| match color.as_str() {
+            SHINY_GOLD => (SHINY_GOLD_LEVEL, color),
+            _ => (rng.below(LEVELS), color),
+        })
+        .collect();
+    // Deepest first, so the bags a bag can hold are known by the time it is filled
+    bags.sort_by(|a, b| b.cmp(a));
+
+    let mut rules = Vec::new();
+    // Bags that hold shiny gold, or are it, along with their level
+    let mut holders: Vec<(usize, &String)> = Vec::new();
+    for &(level, color) in &bags {
+        let deeper = &bags[..bags.partition_point(|&(other, _)| other > level)];
+
+        // Shiny gold holds something, otherwise part 2 has nothing to count
+        let count = match color.as_str() {
+            SHINY_GOLD => rng.below(3) + 2,
+            _ => rng.below(5),
+        };
+        let mut inside: Vec<&String> = Vec::new();
+        for _ in 0..count.min(deeper.len()) {
+            let inner = rng.pick(deeper).1;
+            if !inside.contains(&inner) {
+                inside.push(inner);
+            }
+        }
+        // Picked at random hardly any bags would end up holding shiny gold
+        let deeper_holders: Vec<&String> = holders
+            .iter()
+            .filter(|&&(other, _)| other > level)
+            .map(|&(_, holder)| holder)
+            .collect();
+        if !deeper_holders.is_empty() && rng.chance(0.5) {
+            let holder = *rng.pick(&deeper_holders);
+            if !inside.contains(&holder) {
+                inside.push(holder);
+            }
+        }
+
+        if color == SHINY_GOLD || inside.iter().any(|&inner| deeper_holders.contains(&inner)) {
+            holders.push((level, color));
+        }
+
+        let contents: Vec<String> = inside
+            .iter()
+            .map(|inner| match rng.below(5) + 1 {
+                1 => format!("1 {} bag", inner),
+                count => format!("{} {} bags", count, inner),
+            })
+            .collect();
+        if contents.is_empty() {
+            rules.push(format!("{} bags contain no other bags.", color));
+        } else {
+            rules.push(format!("{} bags contain {}.", color, contents.join(", ")));
+        }
+    }
+    rng.shuffle(&mut rules);
+
+    generate::lines(rules)
+}
+
+/// Each line is a rule such as `light red bags contain 1 bright white bag, 2 muted yellow bags.`,
+/// and every bag that is contained must have a rule of its own
+pub fn validate(input: &str) -> Vec<Error> {
+    let mut problems = Problems::new();
+    let mut described = HashSet::new();
+    let mut contained = Vec::new();
+    for (i, line) in input.lines().enumerate() {
+        let n = i + 1;
+        let (color, contents) = match line.split_once(" bags contain ") {
+            Some(split) => split,
+            None => {
+                problems.line(n, "expected '<color> bags contain' in", line);
+                continue;
+            }
+        };
+        if color.split(' ').count() != 2 {
+            problems.at(n, line, color, "expected a two word color, found");
+        }
+        described.insert(color);
+
+        let contents = match contents.strip_suffix('.') {
+            Some(contents) => contents,
+            None => {
+                problems.at(n, line, contents, "expected a '.' at the end of");
+                continue;
+            }
+        };
+        if contents == "no other bags" {
+            continue;
+        }
+
+        for item in contents.split(", ") {
+            let words: Vec<&str> = item.split(' ').collect();
+            match words[..] {
+                [count, _, _, "bag"] | [count, _, _, "bags"] => {
+                    problems.value::<u32>(n, line, count);
+                    let color = &item[count.len() + 1..item.rfind(' ').unwrap_or(item.len())];
+                    contained.push((n, line, color));
+                }
+                _ => problems.at(n, line, item, "expected '<count> <color> bags', found"),
+            }
+        }
+    }
+
+    for (n, line, color) in contained {
+        if !described.contains(color) {
+            problems.at(n, line, color, "no rule for bag");
+        }
+    }
+    problems.into_errors()
+}
+
+fn part_1(bags: &Bags) -> u32 {
+    let mut open = VecDeque::new();
+    let mut closed = HashSet::new();
+
+    let first = SHINY_GOLD.to_string();
+    open.push_front(&first);
+
+    while open.len() > 0 {
+        let val = open.pop_back().unwrap();
+        closed.insert(val);
+        metrics::count("nodes_expanded", 1);
+
+        bags.contents
+            .iter()
+            .filter(|kv| kv.1.contents.contains_key(val) && !closed.contains(kv.0))
+            .for_each(|kv| open.push_front(&kv.0));
+    }
+
+    closed.len() as u32 - 1
+}
+
+#[derive(Default)]
+struct BagTree {
+    next: Option<Vec<(u32, BagTree)>>,
+    count: u32,
+}
+
+impl BagTree {
+    pub fn construct(bags: &Bags, key: String) -> BagTree {
+        let bag = bags.contents.get(&key);
+
+        match bag {
+            Some(bag) => {
+                let below = {
+                    let children: Vec<(u32, BagTree)> = bag
+                        .contents
+                        .iter()
+                        .map(|kv| (*kv.1, BagTree::construct(bags, kv.0.to_string())))
+                        .collect();
+
+                    (children.len() != 0).then_some(children)
+                };
+
+                BagTree {
+                    next: below,
+                    count: bag.contents.values().sum(),
+                }
+            }
+            None => BagTree::default(),
+        }
+    }
+
+    pub fn print_tree(&self) {
+        if let Some(children) = &self.next {
+            children.iter().for_each(|c| c.1.print_tree());
+        }
+    }
+
+    pub fn part_2(&self) -> u32 {
+        let mut sum = self.count;
+        if let Some(children) = &self.next {
+            for child in children {
+                sum += child.1.part_2() * child.0;
+            }
+        }
+
+        sum
+    }
+}
@@ -0,0 +1,309 @@
+use std::{collections::HashSet, convert::TryFrom};
+
+use crate::bench::{timed, Phase};
+use crate::error::{parse_lines, parse_value, split_once, Error, Result};
+use crate::generate::{self, Rng};
+use crate::metrics;
+use crate::solution::Solution;
+use crate::validate::Problems;
+
+pub fn aoc_8(input: &str) -> Result<Solution> {
+    let program = timed(Phase::Parse, || Program::new(input))?;
+
+    let part1 = timed(Phase::Part1, || part_1_buckets(&program));
+    let part2 = timed(Phase::Part2, || part_2(&program))?;
+
+    Ok(Solution::new(part1, part2))
+}
+
+/// Each line is an instruction such as `jmp -4`.
+/// Jumps must stay within the program or land right after its end,
+/// and so must nops, as part 2 runs them as jumps.
+pub fn validate(input: &str) -> Vec<Error> {
+    let mut problems = Problems::new();
+    let len = input.lines().count() as i64;
+    for (i, line) in input.lines().enumerate() {
+        let (op, arg) = match line.split_once(' ') {
+            Some(split) => split,
+            None => {
+                problems.line(i + 1, "expected 'opcode argument' in", line);
+                continue;
+            }
+        };
+
+        if OpCode::try_from(op).is_err() {
+            problems.at(i + 1, line, op, "unknown opcode");
+        }
+        let arg = problems.value::<i64>(i + 1, line, arg);
+        let outside = match OpCode::try_from(op) {
+            Ok(OpCode::JMP) => Some("jump lands outside of the program"),
+            Ok(OpCode::NOP) => Some("nop would land outside of the program as a jump"),
+            _ => None,
+        };
+        if let (Some(message), Some(offset)) = (outside, arg) {
+            if !(0..=len).contains(&(i as i64 + offset)) {
+                problems.at(i + 1, line, line, message);
+            }
+        }
+    }
+    problems.into_errors()
+}
+
+/// A random program of `size` instructions, 600 by default, that loops unless one instruction is fixed
+///
+/// Running from the start only ever moves forward until a backward jump, the one to fix,
+/// sends it back into code that leads straight to that jump again.
+/// No other swap can help: jumps before it never pass it and swapped nops only land before it.
+/// Once fixed, what follows moves forward to the end of the program.
+pub fn generate(rng: &mut Rng, size: Option<usize>) -> String {
+    let len = size.unwrap_or(600).max(4);
+    // Kept in the first half, leaving at least half of the program to run once it is fixed
+    let fix = len / 4 + rng.below(len / 4);
+
+    let mut code = Vec::with_capacity(len);
+    generate_path(rng, &mut code, fix);
+    code.push(format!("jmp -{}", rng.below(fix) + 1));
+    generate_path(rng, &mut code, len);
+
+    generate::lines(code)
+}
+
+/// Appends code that always moves forward until `end`.
+/// Swapping any of it to a nop or jump still cannot get past `end`.
+fn generate_path(rng: &mut Rng, code: &mut Vec<String>, end: usize) {
+    let nop =
+        |rng: &mut Rng, line: usize| format!("nop {:+}", rng.below(end + 1) as i64 - line as i64);
+    let acc = |rng: &mut Rng| format!("acc {:+}", rng.range(-50..=50));
+
+    while code.len() < end {
+        let line = code.len();
+        match rng.below(10) {
+            0..=4 => code.push(acc(rng)),
+            5..=6 => code.push(nop(rng, line)),
+            _ => {
+                let skip = rng.below((end - line).min(8));
+                code.push(format!("jmp +{}", skip + 1));
+                // Only reached once a jump before it is swapped, then leads to where that jump went
+                for _ in 0..skip {
+                    let line = code.len();
+                    if rng.chance(0.7) {
+                        code.push(acc(rng));
+                    } else {
+                        code.push(nop(rng, line));
+                    }
+                }
+            }
+        }
+    }
+}
+
+// Since the problem space is small a simple bucket can track our progress.
+// A stack allocated array was faster (~6µs against ~14µs) but capped the program length
+pub fn part_1_buckets(program: &Program) -> i64 {
+    let mut state = State::default();
+
+    // A bucket per instruction and one for the end of the program
+    let mut ran_ops = vec![0u8; program.code.len() + 1];
+
+    loop {
+        let cl = state.current_line;
+        program.run_line(cl, &mut state);
+
+        ran_ops[cl] = 1;
+
+        if ran_ops[state.current_line] != 0 {
+            break;
+        }
+    }
+
+    state.accumulator
+}
+
+// HashSet (~220µs) requires no knowledge of space ahead of time at a huge time cost
+pub fn part_1_hashset(program: &Program) -> i64 {
+    let mut state = State::default();
+    let mut ran_ops = HashSet::new();
+    ran_ops.insert(state.current_line);
+
+    loop {
+        let cl = state.current_line;
+        program.run_line(cl, &mut state);
+
+        if !ran_ops.insert(state.current_line) {
+            break;
+        }
+    }
+
+    state.accumulator
+}
+
+fn part_2(program: &Program) -> Result<i64> {
+    let mut state = State::default();
+    let mut ran_ops = vec![0u8; program.code.len() + 1];
+    let mut op_seq = Vec::new();
+    let mut looped_at = 0usize;
+
+    loop {
+        let cl = state.current_line;
+        op_seq.push(cl);
+
+        program.run_line(cl, &mut state);
+
+        ran_ops[cl] = 1;
+        if ran_ops[state.current_line] != 0 {
+            looped_at = state.current_line;
+            break;
+        }
+    }
+
+    // let mut found_start = false;
+    // for i in 0..op_seq.len() {
+    //     let val = op_seq[i];
+
+    //     if !found_start {
+    //         found_start = val == looped_at;
+    //         continue;
+    //     }
+    //     if program.code[val].op_code == OpCode::ACC {
+    //         continue;
+    //     }
+    //     if let Some(acc) = part_2_rerunner(&program, val) {
+    //         return acc;
+    //     }
+    // }
+
+    for line in op_seq
+        .iter()
+        .skip_while(|v| *v != &looped_at)
+        .skip(1)
+        .take_while(|v| *v != &looped_at)
+        .filter(|v| program.code[**v].op_code != OpCode::ACC)
+    {
+        if let Some(acc) = part_2_rerunner(&program, *line) {
+            return Ok(acc);
+        }
+    }
+
+    Err(Error::new("no single instruction swap terminates"))
+}
+
+fn part_2_rerunner(program: &Program, replace: usize) -> Option<i64> {
+    let mut state = State::default();
+    let mut ran_ops = vec![0u8; program.code.len() + 1];
+
+    while !program.is_eop(state.current_line) {
+        let cl = state.current_line;
+
+        if cl == replace {
+            let op = match program.code[cl].op_code {
+                OpCode::JMP => OpCode::NOP,
+                OpCode::NOP => OpCode::JMP,
+                _ => OpCode::UNKNOWN,
+            };
+
+            program.run_line_with_op(cl, op, &mut state);
+        } else {
+            program.run_line(cl, &mut state);
+        }
+
+        ran_ops[cl] = 1;
+        if ran_ops[state.current_line] != 0 {
+            return None;
+        }
+    }
+
+    Some(state.accumulator)
+}
+
+/// A handheld console program, one instruction per line such as `acc +3`
+pub struct Program {
+    code: Vec<Op>,
+}
+
+impl Program {
+    pub fn new(input: &str) -> Result<Program> {
+        let code = parse_lines(input.lines(), |l| {
+            let (op, num) = Self::parse_line(l)?;
+            Ok(Op {
+                op_code: OpCode::try_from(op)?,
+                value: num,
+            })
+        })?;
+
+        Ok(Program { code: code })
+    }
+
+    fn parse_line(line: &str) -> Result<(&str, i64)> {
+        let (op, num) = split_once(line, " ")?;
+        let num: i64 = parse_value(num)?;
+
+        Ok((op, num))
+    }
+
+    pub fn run_line(&self, line: usize, state: &mut State) {
+        let op = &self.code[line];
+        self.run_op(op, &op.op_code, state);
+    }
+
+    pub fn run_line_with_op(&self, line: usize, op_code: OpCode, state: &mut State) {
+        let op = &self.code[line];
+        self.run_op(op, &op_code, state);
+    }
+
+    fn run_op(&self, op: &Op, op_code: &OpCode, state: &mut State) {
+        metrics::count("instructions", 1);
+        match op_code {
+            OpCode::JMP => state.current_line = (state.current_line as i64 + op.value) as usize,
+            OpCode::ACC => {
+                state.accumulator += op.value;
+                state.current_line += 1;
+            }
+            OpCode::NOP => {
+                state.current_line += 1;
+            }
+            OpCode::UNKNOWN => (),
+        }
+    }
+
+    /// Whether `line` is past the last instruction, which means the program terminated
+    pub fn is_eop(&self, line: usize) -> bool {
+        line >= self.code.len()
+    }
+
+    pub fn code(&self) -> &[Op] {
+        &self.code
+    }
+}
+
+pub struct Op {
+    pub op_code: OpCode,
+    pub value: i64,
+}
+
+#[derive(Default, Copy, Clone)]
+pub struct State {
+    pub accumulator: i64,
+    pub current_line: usize,
+}
+
+#[derive(Eq, PartialEq, Copy, Clone, Debug)]
+#[repr(u32)]
+pub enum OpCode {
+    UNKNOWN,
+    JMP,
+    ACC,
+    NOP,
+}
+
+impl TryFrom<&str> for OpCode {
+    type Error = Error;
+
+    fn try_from(val: &str) -> Result<Self> {
+        match val {
+            "jmp" => Ok(OpCode::JMP),
+            "acc" => Ok(OpCode::ACC),
+            "nop" => Ok(OpCode::NOP),
+            _ => Err(Error::invalid("unknown opcode", val)),
+        }
+    }
+}
@@ -0,0 +1,86 @@
+use std::collections::HashSet;
+
+use crate::bench::{timed, Phase};
+use crate::error::{parse_lines, parse_value, Error, Result};
+use crate::solution::Solution;
+use crate::validate;
+
+const WINDOW_SIZE: usize = 25;
+
+pub fn aoc_9(input: &str) -> Result<Solution> {
+    let lines: Vec<u64> = timed(Phase::Parse, || parse_lines(input.lines(), parse_value))?;
+
+    let invalid_number = timed(Phase::Part1, || solve_part1(&lines, WINDOW_SIZE))?;
+    let decrypt = timed(Phase::Part2, || {
+        solve_part2(&lines, invalid_number).ok_or_else(|| {
+            Error::new(format!(
+                "no run of two or more numbers sums to {}",
+                invalid_number
+            ))
+        })
+    })?;
+
+    Ok(Solution::new(invalid_number, decrypt))
+}
+
+/// Each line is a number, and there must be more of them than the preamble
+pub fn validate(input: &str) -> Vec<Error> {
+    let mut problems = validate::numbers(input);
+    if input.lines().count() <= WINDOW_SIZE {
+        problems.push(Error::new(format!(
+            "expected more than the {} numbers of the preamble",
+            WINDOW_SIZE
+        )));
+    }
+    problems
+}
+
+/// Finds the first number that is not the sum of two of the `window` numbers before it
+pub fn solve_part1(lines: &[u64], window: usize) -> Result<u64> {
+    lines
+        .windows(window + 1)
+        .try_find(|&w| Some(validate_line(&w[..window], w[window]).is_none()))
+        .unwrap_or(None)
+        .map(|w| w[window])
+        .ok_or_else(|| Error::new("no number breaks the preamble rule"))
+}
+
+fn validate_line(window: &[u64], current: u64) -> Option<u64> {
+    for j in 0..window.len() {
+        for k in j + 1..window.len() {
+            if window[j] + window[k] == current {
+                return Some(current);
+            }
+        }
+    }
+    None
+}
+
+/// Finds a contiguous run of numbers summing to `invalid_number`, giving the sum of its smallest and largest
+pub fn solve_part2(lines: &[u64], invalid_number: u64) -> Option<u64> {
+    let mut sum = 0;
+    for i in 0..lines.len() {
+        sum = lines[i];
+        for j in i + 1..lines.len() {
+            sum += lines[j];
+
+            if sum > invalid_number {
+                break;
+            } else if sum == invalid_number {
+                return Some(find_sum(lines, i, j));
+            }
+        }
+    }
+
+    None
+}
+
+fn find_sum(lines: &[u64], start: usize, end: usize) -> u64 {
+    let (min, max) = lines
+        .iter()
+        .skip(start)
+        .take(end - start)
+        .fold((1 << 63, 0), |a, &v| (a.0.min(v), a.1.max(v)));
+
+    min + max
+}
@@ -0,0 +1,47 @@
+use super::Day;
+
+pub mod aoc1;
+pub mod aoc2;
+pub mod aoc3;
+pub mod aoc4;
+pub mod aoc5;
+pub mod aoc6;
+pub mod aoc7;
+pub mod aoc8;
+pub mod aoc9;
+pub mod aoc10;
+pub mod aoc11;
+pub mod aoc12;
+pub mod aoc13;
+pub mod aoc14;
+pub mod aoc15;
+pub mod aoc16;
+pub mod aoc17;
+
+/// Every solved day of 2020, in order.
+/// A new day only needs its module declared above and an entry here.
+pub const DAYS: &[Day] = &[
+    Day::new(1, "Report Repair", aoc1::aoc_1, aoc1::validate).with_reporter(aoc1::report),
+    Day::new(2, "Password Philosophy", aoc2::aoc_2, aoc2::validate).with_reporter(aoc2::report),
+    Day::new(3, "Toboggan Trajectory", aoc3::aoc_3, aoc3::validate),
+    Day::new(4, "Passport Processing", aoc4::aoc_4, aoc4::validate),
+    Day::new(5, "Binary Boarding", aoc5::aoc_5, aoc5::validate),
+    Day::new(6, "Custom Customs", aoc6::aoc_6, aoc6::validate),
+    Day::new(7, "Handy Haversacks", aoc7::aoc_7, aoc7::validate).with_generator(aoc7::generate),
+    Day::new(8, "Handheld Halting", aoc8::aoc_8, aoc8::validate).with_generator(aoc8::generate),
+    Day::new(9, "Encoding Error", aoc9::aoc_9, aoc9::validate),
+    Day::new(10, "Adapter Array", aoc10::aoc_10, aoc10::validate),
+    Day::new(11, "Seating System", aoc11::aoc_11, aoc11::validate).with_generator(aoc11::generate),
+    Day::new(12, "Rain Risk", aoc12::aoc_12, aoc12::validate)
+        .with_generator(aoc12::generate),
+    Day::new(13, "Shuttle Search", aoc13::aoc_13, aoc13::validate),
+    Day::new(14, "Docking Data", aoc14::aoc_14, aoc14::validate).with_generator(aoc14::generate),
+    Day::new(
+        15,
+        "Rambunctious Recitation",
+        aoc15::aoc_15,
+        aoc15::validate,
+    ),
+    Day::new(16, "Ticket Translation", aoc16::aoc_16, aoc16::validate),
+    Day::new(17, "Conway Cubes", aoc17::aoc_17, aoc17::validate),
+];
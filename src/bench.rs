@@ -0,0 +1,177 @@
+use std::cell::Cell;
+use std::time::{Duration, Instant};
+
+use crate::aoc::DayId;
+use crate::history::Change;
+use crate::solution::{format_duration, Timings};
+
+/// Runs spent warming up before measuring, at the very least
+const MIN_WARMUP_RUNS: u32 = 3;
+const MIN_RUNS: u32 = 10;
+const MAX_RUNS: u32 = 1_000_000;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Phase {
+    Parse,
+    Part1,
+    Part2,
+}
+
+thread_local! {
+    static RECORDED: Cell<Timings> = Cell::new(Timings::default());
+}
+
+/// Runs `f` as one phase of a solver and records how long it took.
+/// Solvers wrap their parsing and each part in this so the runner can report them separately.
+pub fn timed<T>(phase: Phase, f: impl FnOnce() -> T) -> T {
+    let sw = Instant::now();
+    let result = f();
+    let elapsed = sw.elapsed();
+
+    RECORDED.with(|recorded| {
+        let mut timings = recorded.get();
+        let slot = match phase {
+            Phase::Parse => &mut timings.parse,
+            Phase::Part1 => &mut timings.part1,
+            Phase::Part2 => &mut timings.part2,
+        };
+        *slot = Some(slot.unwrap_or_default() + elapsed);
+        recorded.set(timings);
+    });
+
+    result
+}
+
+/// Clears the phases recorded on this thread
+pub fn reset() {
+    RECORDED.with(|recorded| recorded.set(Timings::default()));
+}
+
+/// Takes the phases recorded on this thread since the last reset
+pub fn take() -> Timings {
+    RECORDED.with(|recorded| recorded.replace(Timings::default()))
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct Stats {
+    pub min: Duration,
+    pub median: Duration,
+    pub p95: Duration,
+}
+
+impl Stats {
+    fn from_samples(samples: &mut [Duration]) -> Option<Stats> {
+        if samples.is_empty() {
+            return None;
+        }
+
+        samples.sort_unstable();
+        let p95 = (samples.len() * 95).div_ceil(100);
+        Some(Stats {
+            min: samples[0],
+            median: samples[samples.len() / 2],
+            p95: samples[p95.max(1) - 1],
+        })
+    }
+}
+
+#[derive(Debug)]
+pub struct BenchResult {
+    pub warmup_runs: u32,
+    pub runs: u32,
+    pub parse: Option<Stats>,
+    pub part1: Option<Stats>,
+    pub part2: Option<Stats>,
+    pub total: Stats,
+}
+
+/// Repeatedly runs `f`, adapting the number of runs to fit roughly in `target` after warming up
+pub fn bench(target: Duration, mut f: impl FnMut()) -> BenchResult {
+    let warmup = Instant::now();
+    let mut warmup_runs = 0;
+    while warmup_runs < MIN_WARMUP_RUNS || warmup.elapsed() < target / 10 {
+        f();
+        warmup_runs += 1;
+    }
+    let per_run = warmup.elapsed() / warmup_runs;
+
+    let runs = (target.as_nanos() / per_run.as_nanos().max(1)) as u32;
+    let runs = runs.clamp(MIN_RUNS, MAX_RUNS);
+
+    let mut parse = Vec::new();
+    let mut part1 = Vec::new();
+    let mut part2 = Vec::new();
+    let mut total = Vec::with_capacity(runs as usize);
+    for _ in 0..runs {
+        reset();
+        let sw = Instant::now();
+        f();
+        total.push(sw.elapsed());
+
+        let timings = take();
+        parse.extend(timings.parse);
+        part1.extend(timings.part1);
+        part2.extend(timings.part2);
+    }
+
+    BenchResult {
+        warmup_runs,
+        runs,
+        parse: Stats::from_samples(&mut parse),
+        part1: Stats::from_samples(&mut part1),
+        part2: Stats::from_samples(&mut part2),
+        total: Stats::from_samples(&mut total).unwrap(),
+    }
+}
+
+impl BenchResult {
+    /// The stats of each phase by name, phases the solver does not time are left out
+    pub fn rows(&self) -> [(&'static str, Option<Stats>); 4] {
+        [
+            ("parse", self.parse),
+            ("part1", self.part1),
+            ("part2", self.part2),
+            ("total", Some(self.total)),
+        ]
+    }
+
+    /// Prints the stats of every phase, along with how their medians changed since `changes` were recorded
+    pub fn print(&self, day: DayId, changes: &[Change]) {
+        println!(
+            "AOC {} ({} runs after {} warm-up)",
+            day, self.runs, self.warmup_runs
+        );
+        println!(
+            "\t{:<8}{:>12}{:>12}{:>12}{:>12}",
+            "", "min", "median", "p95", "vs last"
+        );
+
+        for (phase, stats) in self.rows().iter() {
+            let stats = match stats {
+                Some(stats) => stats,
+                None => continue,
+            };
+            let name = match *phase {
+                "parse" => "Parse",
+                "part1" => "Part 1",
+                "part2" => "Part 2",
+                _ => "Total",
+            };
+            let change = changes
+                .iter()
+                .find(|change| change.phase == *phase)
+                .map_or("-".to_string(), |change| {
+                    format!("{:+.1}%", change.ratio() * 100.0)
+                });
+
+            println!(
+                "\t{:<8}{:>12}{:>12}{:>12}{:>12}",
+                name,
+                format_duration(stats.min),
+                format_duration(stats.median),
+                format_duration(stats.p95),
+                change
+            );
+        }
+    }
+}
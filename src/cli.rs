@@ -0,0 +1,377 @@
+use std::ops::RangeInclusive;
+use std::slice::Iter;
+use std::time::Duration;
+
+use advent_of_code::aoc::y2020::aoc3::Slope;
+use advent_of_code::aoc::{find_year, latest_year, DayId, Year, FIRST_YEAR, YEARS};
+use advent_of_code::input::{self, DEFAULT_SET};
+use advent_of_code::output::Format;
+
+const DEFAULT_ANSWERS: &str = "./answers.toml";
+const DEFAULT_BENCH_TIME: Duration = Duration::from_secs(1);
+const DEFAULT_HISTORY: &str = "./bench_history.csv";
+const DEFAULT_THRESHOLD: f64 = 0.1;
+
+pub const USAGE: &str = "Usage:
+    aoc run [<year>] <day>... [--input <path> | --input-set <name>] [--answers <path>] [--format <format>] [--metrics]
+    aoc run 2020 3 [--slopes <slopes>] [--input <path> | --input-set <name>]
+    aoc run <year> | --all [--input-set <name>] [--parallel] [--format <format>] [--metrics]
+    aoc bench [<year>] <day>... [--input <path> | --input-set <name>] [--time <seconds>] [--history <path> | --no-history] [--threshold <percent>]
+    aoc check [<year>] <day>... [--input <path> | --input-set <name>]
+    aoc watch [<year>] <day> [--input <path> | --input-set <name>] [--answers <path>] [--metrics]
+    aoc gen [<year>] <day> [--seed <number>] [--size <number>]
+    aoc new [<year>] <day> [--title <title>]
+    aoc report [<year>] <day> [--input <path> | --input-set <name>] [--format <format>]
+    aoc list
+
+Days can be given as single days (7) or ranges (3..9, 3..=9), after the year they belong to (2020 7).
+A year on its own selects all of its days, days given without a year belong to the latest one.
+Inputs are validated before a day is run or benchmarked, check lists every problem found in them.
+new adds a day to its year's module and registry, with empty inputs and a failing example test.
+report explains how a day reached its answers, for the days that support it.
+Inputs are read from ./inputs or the directory in $AOC_INPUTS, list shows the input sets of each day.
+
+Options:
+    --all             Select every available day of every year
+    --parallel        Run the days on a thread pool and print a summary table
+    --input <path>    Read the input from <path> instead of ./inputs/<year>/aoc_<day>_input.txt, - for stdin
+    --input-set <name>
+                      Read the inputs from a named set, such as example1 or a directory in ./inputs/<year>, defaults to personal
+    --answers <path>  Check answers against <path>, defaults to ./answers.toml or the answers.toml of the input set
+    --time <seconds>  Time to spend benchmarking each day, defaults to 1
+    --history <path>  Compare benchmarks to and record them in <path>, defaults to ./bench_history.csv for the personal inputs
+    --no-history      Do not compare or record benchmarks
+    --threshold <percent>
+                      Slowdown of a median over the last benchmark that counts as a regression, defaults to 10
+    --format <format> Print run results or a report as text, json or csv, defaults to text
+    --metrics         Report what the solvers counted while running, such as instructions executed
+    --seed <number>   Seed of the generated input, defaults to one taken from the clock
+    --size <number>   Size of the generated input, what it counts and its default depend on the day
+    --title <title>   Title of a new day, defaults to 'Day <day>'
+    --slopes <slopes> Slopes whose trees part 2 of 2020 day 3 multiplies, as right,down pairs such as 1,1:3,1:1,2";
+
+pub enum Command {
+    Help,
+    List,
+    Run(Options),
+    Bench(Options),
+    Check(Options),
+    Watch(Options),
+    Gen(Options),
+    New(Options),
+    Report(Options),
+}
+
+pub struct Options {
+    pub days: Vec<DayId>,
+    pub input: Option<String>,
+    /// A named input set other than the personal one
+    pub input_set: Option<String>,
+    /// Every answers file to check against, a named set has one per year
+    pub answers: Vec<String>,
+    pub bench_time: Duration,
+    pub history: Option<String>,
+    /// Relative slowdown that counts as a regression, 0.1 being 10%
+    pub threshold: f64,
+    pub parallel: bool,
+    pub format: Format,
+    /// Whether to count and report solver metrics
+    pub metrics: bool,
+    pub seed: Option<u64>,
+    pub size: Option<usize>,
+    pub title: Option<String>,
+    /// Slopes replacing those of part 2 of 2020 day 3
+    pub slopes: Option<Vec<Slope>>,
+}
+
+impl Command {
+    pub fn parse(args: &[String]) -> Result<Command, String> {
+        let (command, args) = match args.split_first() {
+            Some((command, args)) => (command.as_str(), args),
+            None => return Ok(Command::Help),
+        };
+
+        match command {
+            "help" | "--help" | "-h" => Ok(Command::Help),
+            "list" => Ok(Command::List),
+            "run" => {
+                let options = Options::parse(args)?;
+                if options.slopes.is_some() && options.parallel {
+                    return Err("--slopes cannot be combined with --parallel".to_string());
+                }
+                Ok(Command::Run(options))
+            }
+            "bench" => {
+                let options = Options::parse(args)?;
+                // Days running next to each other would skew the measurements
+                if options.parallel {
+                    return Err("--parallel cannot be used with bench".to_string());
+                }
+                if options.format != Format::Text {
+                    return Err("--format can only be used with run".to_string());
+                }
+                if options.metrics {
+                    return Err("--metrics cannot be used with bench".to_string());
+                }
+                if options.slopes.is_some() {
+                    return Err("--slopes cannot be used with bench".to_string());
+                }
+                Ok(Command::Bench(options))
+            }
+            "check" => {
+                let options = Options::parse(args)?;
+                if options.parallel {
+                    return Err("--parallel cannot be used with check".to_string());
+                }
+                if options.format != Format::Text {
+                    return Err("--format can only be used with run".to_string());
+                }
+                if options.metrics {
+                    return Err("--metrics cannot be used with check".to_string());
+                }
+                if options.slopes.is_some() {
+                    return Err("--slopes cannot be used with check".to_string());
+                }
+                Ok(Command::Check(options))
+            }
+            "watch" => {
+                let options = Options::parse(args)?;
+                if options.days.len() != 1 {
+                    return Err("watch takes a single day".to_string());
+                }
+                // Each run is printed as text as soon as it finishes
+                if options.parallel {
+                    return Err("--parallel cannot be used with watch".to_string());
+                }
+                if options.format != Format::Text {
+                    return Err("--format cannot be used with watch".to_string());
+                }
+                if options.slopes.is_some() {
+                    return Err("--slopes cannot be used with watch".to_string());
+                }
+                Ok(Command::Watch(options))
+            }
+            "gen" => {
+                let options = Options::parse(args)?;
+                if options.days.len() != 1 {
+                    return Err("gen takes a single day".to_string());
+                }
+                Ok(Command::Gen(options))
+            }
+            "new" => {
+                let options = Options::parse(args)?;
+                if options.days.len() != 1 {
+                    return Err("new takes a single day".to_string());
+                }
+                Ok(Command::New(options))
+            }
+            "report" => {
+                let options = Options::parse(args)?;
+                if options.days.len() != 1 {
+                    return Err("report takes a single day".to_string());
+                }
+                if options.parallel {
+                    return Err("--parallel cannot be used with report".to_string());
+                }
+                if options.metrics {
+                    return Err("--metrics cannot be used with report".to_string());
+                }
+                if options.slopes.is_some() {
+                    return Err("--slopes cannot be used with report".to_string());
+                }
+                Ok(Command::Report(options))
+            }
+            _ => Err(format!("Unknown command '{}'", command)),
+        }
+    }
+}
+
+impl Options {
+    fn parse(args: &[String]) -> Result<Options, String> {
+        let mut options = Options {
+            days: Vec::new(),
+            input: None,
+            input_set: None,
+            answers: Vec::new(),
+            bench_time: DEFAULT_BENCH_TIME,
+            history: None,
+            threshold: DEFAULT_THRESHOLD,
+            parallel: false,
+            format: Format::Text,
+            metrics: false,
+            seed: None,
+            size: None,
+            title: None,
+            slopes: None,
+        };
+        let mut all = false;
+        let mut no_history = false;
+        let mut specs = Vec::new();
+
+        let mut args = args.iter();
+        while let Some(arg) = args.next() {
+            match arg.as_str() {
+                "--all" => all = true,
+                "--parallel" => options.parallel = true,
+                "--metrics" => options.metrics = true,
+                "--input" => options.input = Some(value(&mut args, arg)?),
+                "--input-set" => options.input_set = Some(value(&mut args, arg)?),
+                "--answers" => options.answers = vec![value(&mut args, arg)?],
+                "--format" => options.format = value(&mut args, arg)?.parse()?,
+                "--time" => {
+                    let time = value(&mut args, arg)?;
+                    let time: f64 = time
+                        .parse()
+                        .map_err(|_| format!("Invalid time '{}'", time))?;
+                    options.bench_time = Duration::from_secs_f64(time);
+                }
+                "--title" => options.title = Some(value(&mut args, arg)?),
+                "--slopes" => {
+                    let slopes = value(&mut args, arg)?;
+                    let slopes = Slope::parse_list(&slopes)
+                        .map_err(|err| format!("Invalid slopes '{}': {}", slopes, err))?;
+                    options.slopes = Some(slopes);
+                }
+                "--history" => options.history = Some(value(&mut args, arg)?),
+                "--no-history" => no_history = true,
+                "--threshold" => {
+                    let threshold = value(&mut args, arg)?;
+                    let threshold: f64 = threshold
+                        .parse()
+                        .map_err(|_| format!("Invalid threshold '{}'", threshold))?;
+                    options.threshold = threshold / 100.0;
+                }
+                "--seed" => {
+                    let seed = value(&mut args, arg)?;
+                    let seed = seed
+                        .parse()
+                        .map_err(|_| format!("Invalid seed '{}'", seed))?;
+                    options.seed = Some(seed);
+                }
+                "--size" => {
+                    let size = value(&mut args, arg)?;
+                    let size = size
+                        .parse()
+                        .map_err(|_| format!("Invalid size '{}'", size))?;
+                    options.size = Some(size);
+                }
+                _ if arg.starts_with("--") => return Err(format!("Unknown option '{}'", arg)),
+                _ => specs.push(arg.as_str()),
+            }
+        }
+        options.days = parse_days(&specs)?;
+
+        if all {
+            if !options.days.is_empty() {
+                return Err("--all cannot be combined with explicit days".to_string());
+            }
+            options.days = YEARS.iter().flat_map(Year::ids).collect();
+        }
+
+        if options.days.is_empty() {
+            return Err("No days given".to_string());
+        }
+        if options.slopes.is_some() && options.days != [DayId::new(2020, 3)] {
+            return Err("--slopes can only be used with 2020 day 3".to_string());
+        }
+        if options.input.is_some() && options.days.len() != 1 {
+            return Err("--input can only be used with a single day".to_string());
+        }
+
+        if options.input_set.as_deref() == Some(DEFAULT_SET) {
+            options.input_set = None;
+        }
+        if options.input.is_some() && options.input_set.is_some() {
+            return Err("--input cannot be combined with --input-set".to_string());
+        }
+        // Answers and benchmarks of day 3 belong to the slopes of the puzzle
+        let personal =
+            options.input.is_none() && options.input_set.is_none() && options.slopes.is_none();
+
+        // The default answers belong to the personal inputs, other sets keep theirs next to their inputs
+        if options.answers.is_empty() {
+            options.answers = match &options.input_set {
+                Some(set) => {
+                    let mut years: Vec<u16> = options.days.iter().map(|day| day.year).collect();
+                    years.dedup();
+                    years
+                        .into_iter()
+                        .filter_map(|year| input::set_answers(year, set))
+                        .map(|path| path.display().to_string())
+                        .collect()
+                }
+                None if personal => vec![DEFAULT_ANSWERS.to_string()],
+                None => Vec::new(),
+            };
+        }
+
+        // Likewise the default history only holds benchmarks of the personal inputs
+        if no_history {
+            if options.history.is_some() {
+                return Err("--history cannot be combined with --no-history".to_string());
+            }
+        } else if options.history.is_none() && personal {
+            options.history = Some(DEFAULT_HISTORY.to_string());
+        }
+
+        Ok(options)
+    }
+}
+
+fn value(args: &mut Iter<String>, option: &str) -> Result<String, String> {
+    args.next()
+        .cloned()
+        .ok_or_else(|| format!("{} requires a value", option))
+}
+
+/// Turns the day specs into days, each year applying to the specs after it
+fn parse_days(specs: &[&str]) -> Result<Vec<DayId>, String> {
+    let mut groups: Vec<(u16, Vec<&str>)> = Vec::new();
+    for &spec in specs {
+        match parse_year(spec) {
+            Some(year) => groups.push((year, Vec::new())),
+            None => match groups.last_mut() {
+                Some((_, days)) => days.push(spec),
+                None => groups.push((latest_year(), vec![spec])),
+            },
+        }
+    }
+
+    let mut days = Vec::new();
+    for (year, specs) in groups {
+        if specs.is_empty() {
+            let found = find_year(year).ok_or_else(|| format!("No solved days in {}", year))?;
+            days.extend(found.ids());
+        }
+        for spec in specs {
+            days.extend(parse_range(spec)?.map(|day| DayId::new(year, day)));
+        }
+    }
+    Ok(days)
+}
+
+fn parse_year(spec: &str) -> Option<u16> {
+    spec.parse().ok().filter(|&year| year >= FIRST_YEAR)
+}
+
+fn parse_range(spec: &str) -> Result<RangeInclusive<u32>, String> {
+    let parse_day = |day: &str| {
+        day.parse::<u32>()
+            .map_err(|_| format!("Invalid day '{}'", spec))
+    };
+
+    let range = if let Some((start, end)) = spec.split_once("..=") {
+        parse_day(start)?..=parse_day(end)?
+    } else if let Some((start, end)) = spec.split_once("..") {
+        parse_day(start)?..=parse_day(end)?.saturating_sub(1)
+    } else {
+        let day = parse_day(spec)?;
+        day..=day
+    };
+
+    if range.is_empty() {
+        return Err(format!("Empty day range '{}'", spec));
+    }
+
+    Ok(range)
+}
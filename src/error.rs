@@ -0,0 +1,112 @@
+use std::fmt;
+use std::io;
+use std::str::FromStr;
+
+use crate::aoc::DayId;
+
+pub type Result<T> = std::result::Result<T, Error>;
+
+/// Why a day could not be solved, with as much context as is known about where it went wrong
+#[derive(Debug)]
+pub struct Error {
+    pub day: Option<DayId>,
+    /// 1-based line of the input the error was found on
+    pub line: Option<usize>,
+    /// 1-based column within the line, in characters
+    pub column: Option<usize>,
+    pub message: String,
+    /// The offending piece of input
+    pub text: Option<String>,
+}
+
+impl Error {
+    pub fn new(message: impl Into<String>) -> Error {
+        Error {
+            day: None,
+            line: None,
+            column: None,
+            message: message.into(),
+            text: None,
+        }
+    }
+
+    /// An error caused by a specific piece of the input
+    pub fn invalid(message: impl Into<String>, text: &str) -> Error {
+        Error {
+            text: Some(text.to_string()),
+            ..Error::new(message)
+        }
+    }
+
+    /// Sets the line the error happened on, unless a more specific one is already known
+    pub fn at_line(mut self, line: usize) -> Error {
+        self.line.get_or_insert(line);
+        self
+    }
+
+    pub fn at_column(mut self, column: usize) -> Error {
+        self.column.get_or_insert(column);
+        self
+    }
+
+    pub fn for_day(mut self, day: DayId) -> Error {
+        self.day.get_or_insert(day);
+        self
+    }
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let location: Vec<String> = self
+            .day
+            .map(|day| day.to_string())
+            .into_iter()
+            .chain(self.line.map(|line| format!("line {}", line)))
+            .chain(self.column.map(|column| format!("column {}", column)))
+            .collect();
+        if !location.is_empty() {
+            write!(f, "{}: ", location.join(", "))?;
+        }
+
+        write!(f, "{}", self.message)?;
+        if let Some(text) = &self.text {
+            write!(f, " '{}'", text)?;
+        }
+        Ok(())
+    }
+}
+
+impl std::error::Error for Error {}
+
+impl From<io::Error> for Error {
+    fn from(err: io::Error) -> Error {
+        Error::new(err.to_string())
+    }
+}
+
+/// Parses each line with `f`, adding the line number to any error
+pub fn parse_lines<'a, S, T>(
+    lines: impl IntoIterator<Item = &'a S>,
+    mut f: impl FnMut(&str) -> Result<T>,
+) -> Result<Vec<T>>
+where
+    S: AsRef<str> + ?Sized + 'a,
+{
+    lines
+        .into_iter()
+        .enumerate()
+        .map(|(i, line)| f(line.as_ref()).map_err(|err| err.at_line(i + 1)))
+        .collect()
+}
+
+/// Parses a value such as a number, failing with the text that could not be parsed
+pub fn parse_value<T: FromStr>(text: &str) -> Result<T> {
+    text.parse()
+        .map_err(|_| Error::invalid("invalid value", text))
+}
+
+/// Like `str::split_once`, failing when the separator is missing
+pub fn split_once<'a>(text: &'a str, separator: &str) -> Result<(&'a str, &'a str)> {
+    text.split_once(separator)
+        .ok_or_else(|| Error::invalid(format!("expected '{}' in", separator), text))
+}
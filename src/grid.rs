@@ -0,0 +1,61 @@
+//! A rectangle of single byte cells, the shape of most puzzle maps
+
+use crate::error::{Error, Result};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Grid2D {
+    width: usize,
+    height: usize,
+    /// Every row after the other, so a cell is found without walking its row
+    cells: Vec<u8>,
+}
+
+impl Grid2D {
+    /// Reads a grid with a row per line, every row being as wide as the first
+    pub fn parse(input: &str) -> Result<Grid2D> {
+        let width = input.lines().next().map_or(0, str::len);
+        if width == 0 {
+            return Err(Error::new("empty grid"));
+        }
+
+        let mut cells = Vec::with_capacity(input.len());
+        for (i, line) in input.lines().enumerate() {
+            if line.len() != width {
+                return Err(Error::invalid("row is not as wide as the first", line).at_line(i + 1));
+            }
+            cells.extend_from_slice(line.as_bytes());
+        }
+
+        Ok(Grid2D {
+            width,
+            height: cells.len() / width,
+            cells,
+        })
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    /// The cell at column `x` of row `y`, if it is within the grid
+    pub fn get(&self, x: usize, y: usize) -> Option<u8> {
+        if x < self.width && y < self.height {
+            Some(self.cells[y * self.width + x])
+        } else {
+            None
+        }
+    }
+
+    /// Like `get`, with the grid repeating endlessly to the right so that any column is within it
+    pub fn get_wrapped(&self, x: usize, y: usize) -> Option<u8> {
+        self.get(x % self.width, y)
+    }
+
+    pub fn row(&self, y: usize) -> Option<&[u8]> {
+        (y < self.height).then(|| &self.cells[y * self.width..(y + 1) * self.width])
+    }
+}
@@ -0,0 +1,177 @@
+use std::fs::{self, OpenOptions};
+use std::io::{self, Write};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use crate::aoc::DayId;
+use crate::bench::BenchResult;
+
+const HEADER: &str = "timestamp,year,day,phase,runs,min_ns,median_ns,p95_ns";
+
+/// Files written before the year was recorded, when every benchmark was of 2020
+const OLD_HEADER: &str = "timestamp,day,phase,runs,min_ns,median_ns,p95_ns";
+const OLD_YEAR: u16 = 2020;
+
+/// One benchmarked phase of a day, as stored in the history file
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Entry {
+    /// Seconds since the unix epoch
+    pub timestamp: u64,
+    pub day: DayId,
+    pub phase: String,
+    pub runs: u32,
+    pub min: Duration,
+    pub median: Duration,
+    pub p95: Duration,
+}
+
+/// Benchmark results of earlier runs, kept in a CSV file that every benchmark appends to:
+///
+/// ```text
+/// timestamp,year,day,phase,runs,min_ns,median_ns,p95_ns
+/// 1608336000,2020,8,part1,52301,1840,1920,2210
+/// ```
+#[derive(Debug)]
+pub struct History {
+    path: String,
+    entries: Vec<Entry>,
+}
+
+/// How the median of a phase compares to the last time it was benchmarked
+#[derive(Debug, Clone, Copy)]
+pub struct Change {
+    pub phase: &'static str,
+    pub baseline: Duration,
+    pub current: Duration,
+}
+
+impl Change {
+    /// The relative change, 0.1 being 10% slower than the baseline
+    pub fn ratio(&self) -> f64 {
+        self.current.as_secs_f64() / self.baseline.as_secs_f64().max(f64::MIN_POSITIVE) - 1.0
+    }
+
+    pub fn is_regression(&self, threshold: f64) -> bool {
+        self.ratio() > threshold
+    }
+}
+
+impl History {
+    /// Loads the history at `path`, a missing file simply has no history yet
+    pub fn load(path: &str) -> Result<History, String> {
+        let entries = match fs::read_to_string(path) {
+            Ok(content) => Self::parse(&content).map_err(|err| format!("{}: {}", path, err))?,
+            Err(err) if err.kind() == io::ErrorKind::NotFound => Vec::new(),
+            Err(err) => return Err(format!("{}: {}", path, err)),
+        };
+
+        Ok(History {
+            path: path.to_string(),
+            entries,
+        })
+    }
+
+    fn parse(content: &str) -> Result<Vec<Entry>, String> {
+        let mut entries = Vec::new();
+        for (i, line) in content.lines().enumerate() {
+            let line = line.trim();
+            if line.is_empty() || line == HEADER || line == OLD_HEADER {
+                continue;
+            }
+
+            let error = |msg: &str| format!("line {}: {} '{}'", i + 1, msg, line);
+
+            let mut fields: Vec<&str> = line.split(',').collect();
+            let number = |field: &str| field.parse::<u64>().map_err(|_| error("invalid number in"));
+            let nanos = |field: &str| number(field).map(Duration::from_nanos);
+
+            let year = match fields.len() {
+                8 => number(fields.remove(1))? as u16,
+                7 => OLD_YEAR,
+                _ => return Err(error("expected 8 fields in")),
+            };
+            entries.push(Entry {
+                timestamp: number(fields[0])?,
+                day: DayId::new(year, number(fields[1])? as u32),
+                phase: fields[2].to_string(),
+                runs: number(fields[3])? as u32,
+                min: nanos(fields[4])?,
+                median: nanos(fields[5])?,
+                p95: nanos(fields[6])?,
+            });
+        }
+
+        Ok(entries)
+    }
+
+    /// The most recent entry for a phase of a day
+    pub fn baseline(&self, day: DayId, phase: &str) -> Option<&Entry> {
+        self.entries
+            .iter()
+            .rev()
+            .find(|entry| entry.day == day && entry.phase == phase)
+    }
+
+    /// Compares every phase of a benchmark to its baseline, skipping phases without one
+    pub fn compare(&self, day: DayId, result: &BenchResult) -> Vec<Change> {
+        result
+            .rows()
+            .iter()
+            .filter_map(|&(phase, stats)| {
+                let baseline = self.baseline(day, phase)?;
+                Some(Change {
+                    phase,
+                    baseline: baseline.median,
+                    current: stats?.median,
+                })
+            })
+            .collect()
+    }
+
+    /// Adds a benchmark to the history and appends it to the file
+    pub fn record(&mut self, day: DayId, result: &BenchResult) -> io::Result<()> {
+        let timestamp = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map_or(0, |time| time.as_secs());
+
+        let new_file = fs::metadata(&self.path).is_err();
+        let mut file = OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&self.path)?;
+        if new_file {
+            writeln!(file, "{}", HEADER)?;
+        }
+
+        for &(phase, stats) in result.rows().iter() {
+            let stats = match stats {
+                Some(stats) => stats,
+                None => continue,
+            };
+            let entry = Entry {
+                timestamp,
+                day,
+                phase: phase.to_string(),
+                runs: result.runs,
+                min: stats.min,
+                median: stats.median,
+                p95: stats.p95,
+            };
+
+            writeln!(
+                file,
+                "{},{},{},{},{},{},{},{}",
+                entry.timestamp,
+                entry.day.year,
+                entry.day.day,
+                entry.phase,
+                entry.runs,
+                entry.min.as_nanos(),
+                entry.median.as_nanos(),
+                entry.p95.as_nanos()
+            )?;
+            self.entries.push(entry);
+        }
+
+        Ok(())
+    }
+}
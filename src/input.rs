@@ -0,0 +1,153 @@
+use std::env;
+use std::fmt;
+use std::fs;
+use std::io::{self, Read};
+use std::path::PathBuf;
+
+use crate::aoc::DayId;
+
+/// Environment variable that moves the inputs directory away from `./inputs`
+pub const ROOT_VAR: &str = "AOC_INPUTS";
+
+/// The set made of the top level inputs, usually your own
+pub const DEFAULT_SET: &str = "personal";
+
+/// The directory holding every input, `$AOC_INPUTS` or `./inputs`, with a directory per year.
+/// Each day can have several named sets of inputs:
+///
+/// ```text
+/// inputs/<year>/aoc_<day>_input.txt                the personal set
+/// inputs/<year>/examples/aoc_<day>_example<n>.txt  the example<n> sets
+/// inputs/<year>/<set>/aoc_<day>_input.txt          any other set, such as a colleague's inputs
+/// inputs/<year>/<set>/answers.toml                 and its answers
+/// ```
+pub fn root() -> PathBuf {
+    env::var_os(ROOT_VAR).map_or_else(|| PathBuf::from("./inputs"), PathBuf::from)
+}
+
+fn year_root(year: u16) -> PathBuf {
+    root().join(year.to_string())
+}
+
+/// Where the answers to a named set of a year are kept, examples and the personal set have none there
+pub fn set_answers(year: u16, set: &str) -> Option<PathBuf> {
+    match set {
+        DEFAULT_SET => None,
+        set if example_number(set).is_some() => None,
+        set => Some(year_root(year).join(set).join("answers.toml")),
+    }
+}
+
+fn example_number(set: &str) -> Option<u32> {
+    set.strip_prefix("example")?.parse().ok()
+}
+
+/// The numbered example files of a day, sorted by number
+fn example_files(day: DayId) -> Vec<(u32, PathBuf)> {
+    let prefix = format!("aoc_{}_example", day.day);
+    let mut examples: Vec<(u32, PathBuf)> = fs::read_dir(year_root(day.year).join("examples"))
+        .into_iter()
+        .flatten()
+        .filter_map(|entry| {
+            let path = entry.ok()?.path();
+            let name = path.file_name()?.to_str()?;
+            let number = name
+                .strip_prefix(&prefix)?
+                .strip_suffix(".txt")?
+                .parse()
+                .ok()?;
+            Some((number, path))
+        })
+        .collect();
+    examples.sort();
+    examples
+}
+
+/// Where a day reads its puzzle input from
+pub enum Input {
+    File(PathBuf),
+    Stdin,
+    Text(String),
+}
+
+impl Input {
+    /// The default input of a day, from the personal set
+    pub fn for_day(day: DayId) -> Input {
+        Input::File(year_root(day.year).join(format!("aoc_{}_input.txt", day.day)))
+    }
+
+    /// The input of a day in a named set, which might not exist
+    pub fn for_set(day: DayId, set: &str) -> Input {
+        let year = year_root(day.year);
+        match (set, example_number(set)) {
+            (DEFAULT_SET, _) => Input::for_day(day),
+            (_, Some(number)) => Input::File(
+                year.join("examples")
+                    .join(format!("aoc_{}_example{}.txt", day.day, number)),
+            ),
+            (set, None) => Input::File(year.join(set).join(format!("aoc_{}_input.txt", day.day))),
+        }
+    }
+
+    /// The example inputs of a day, in order
+    pub fn examples(day: DayId) -> Vec<Input> {
+        example_files(day)
+            .into_iter()
+            .map(|(_, path)| Input::File(path))
+            .collect()
+    }
+
+    /// The names of every set that has an input for a day, the personal set first and examples last
+    pub fn sets(day: DayId) -> Vec<String> {
+        let exists = |input: &Input| matches!(input, Input::File(path) if path.is_file());
+
+        let mut named: Vec<String> = fs::read_dir(year_root(day.year))
+            .into_iter()
+            .flatten()
+            .filter_map(|entry| entry.ok()?.file_name().into_string().ok())
+            .filter(|set| set != "examples" && exists(&Input::for_set(day, set)))
+            .collect();
+        named.sort();
+
+        exists(&Input::for_day(day))
+            .then(|| DEFAULT_SET.to_string())
+            .into_iter()
+            .chain(named)
+            .chain(
+                example_files(day)
+                    .into_iter()
+                    .map(|(n, _)| format!("example{}", n)),
+            )
+            .collect()
+    }
+
+    /// Parses an input given on the command line, where `-` means stdin
+    pub fn from_arg(arg: &str) -> Input {
+        match arg {
+            "-" => Input::Stdin,
+            path => Input::File(PathBuf::from(path)),
+        }
+    }
+
+    pub fn read(&self) -> io::Result<String> {
+        match self {
+            Input::File(path) => fs::read_to_string(path),
+            Input::Stdin => {
+                let mut text = String::new();
+                io::stdin().read_to_string(&mut text)?;
+                Ok(text)
+            }
+            Input::Text(text) => Ok(text.clone()),
+        }
+    }
+}
+
+impl fmt::Display for Input {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Input::File(path) => write!(f, "{}", path.display()),
+            Input::Stdin => write!(f, "<stdin>"),
+            Input::Text(_) => write!(f, "<text>"),
+        }
+    }
+}
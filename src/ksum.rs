@@ -0,0 +1,165 @@
+//! Finding `k` entries of a list that add up to a target, the k-sum problem.
+
+use std::collections::HashSet;
+
+/// Entries that add up to the target, by their positions in the list
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Combination {
+    /// Positions of the entries, ascending
+    pub indices: Vec<usize>,
+    /// The entries at those positions
+    pub values: Vec<i64>,
+}
+
+/// Finds `k` entries, each taken from a different position, that add up to `target`.
+/// Returns them in ascending order, or `None` if no such entries exist.
+/// Pairs are found with a hash set in O(n), larger `k` by sorting and then narrowing
+/// two pointers for each choice of the other entries, in O(n^(k-1)).
+pub fn find(entries: &[i64], k: usize, target: i64) -> Option<Vec<i64>> {
+    match k {
+        0 => (target == 0).then(Vec::new),
+        1 => entries.contains(&target).then(|| vec![target]),
+        2 => find_pair(entries, target).map(|(a, b)| vec![a, b]),
+        _ => {
+            let mut sorted = entries.to_vec();
+            sorted.sort_unstable();
+            find_sorted(&sorted, k, target)
+        }
+    }
+}
+
+fn find_pair(entries: &[i64], target: i64) -> Option<(i64, i64)> {
+    let mut seen = HashSet::with_capacity(entries.len());
+    for &entry in entries {
+        let other = target - entry;
+        if seen.contains(&other) {
+            return Some((other.min(entry), other.max(entry)));
+        }
+        seen.insert(entry);
+    }
+    None
+}
+
+/// k-sum over sorted entries, `k` being at least 2
+fn find_sorted(sorted: &[i64], k: usize, target: i64) -> Option<Vec<i64>> {
+    if sorted.len() < k {
+        return None;
+    }
+
+    if k == 2 {
+        let (mut low, mut high) = (0, sorted.len() - 1);
+        while low < high {
+            let sum = sorted[low] + sorted[high];
+            if sum == target {
+                return Some(vec![sorted[low], sorted[high]]);
+            } else if sum < target {
+                low += 1;
+            } else {
+                high -= 1;
+            }
+        }
+        return None;
+    }
+
+    for (i, &first) in sorted.iter().enumerate().take(sorted.len() - k + 1) {
+        // Entries equal to the one before have no partners it did not already have
+        if i > 0 && sorted[i - 1] == first {
+            continue;
+        }
+        if let Some(mut rest) = find_sorted(&sorted[i + 1..], k - 1, target - first) {
+            rest.insert(0, first);
+            return Some(rest);
+        }
+    }
+    None
+}
+
+/// Finds every combination of `k` entries that add up to `target`, ordered by their positions.
+/// Combinations are told apart by position, so equal entries at different places count separately.
+pub fn find_all(entries: &[i64], k: usize, target: i64) -> Vec<Combination> {
+    let mut order: Vec<usize> = (0..entries.len()).collect();
+    order.sort_by_key(|&i| entries[i]);
+    let sorted: Vec<i64> = order.iter().map(|&i| entries[i]).collect();
+
+    let mut found = Vec::new();
+    find_all_sorted(&sorted, k, target, 0, &mut Vec::new(), &mut found);
+
+    let mut combinations: Vec<Combination> = found
+        .into_iter()
+        .map(|positions| {
+            let mut indices: Vec<usize> = positions.iter().map(|&p| order[p]).collect();
+            indices.sort_unstable();
+            let values = indices.iter().map(|&i| entries[i]).collect();
+            Combination { indices, values }
+        })
+        .collect();
+    combinations.sort_by(|a, b| a.indices.cmp(&b.indices));
+    combinations
+}
+
+/// Adds every k-sum over `sorted[start..]` to `found` as positions in `sorted`,
+/// each after the positions already `chosen`
+fn find_all_sorted(
+    sorted: &[i64],
+    k: usize,
+    target: i64,
+    start: usize,
+    chosen: &mut Vec<usize>,
+    found: &mut Vec<Vec<usize>>,
+) {
+    match k {
+        0 => {
+            if target == 0 {
+                found.push(chosen.clone());
+            }
+        }
+        1 => {
+            for i in (start..sorted.len()).filter(|&i| sorted[i] == target) {
+                found.push([&chosen[..], &[i]].concat());
+            }
+        }
+        2 => {
+            if sorted.len() < start + 2 {
+                return;
+            }
+            let (mut low, mut high) = (start, sorted.len() - 1);
+            while low < high {
+                let sum = sorted[low] + sorted[high];
+                if sum < target {
+                    low += 1;
+                } else if sum > target {
+                    high -= 1;
+                } else if sorted[low] == sorted[high] {
+                    // Every entry left is the same, any two of them make a pair
+                    for a in low..high {
+                        for b in a + 1..=high {
+                            found.push([&chosen[..], &[a, b]].concat());
+                        }
+                    }
+                    return;
+                } else {
+                    let low_end = (low..high)
+                        .find(|&i| sorted[i] != sorted[low])
+                        .unwrap_or(high);
+                    let high_start = (low_end..=high)
+                        .find(|&i| sorted[i] == sorted[high])
+                        .unwrap_or(high);
+                    for a in low..low_end {
+                        for b in high_start..=high {
+                            found.push([&chosen[..], &[a, b]].concat());
+                        }
+                    }
+                    low = low_end;
+                    high = high_start - 1;
+                }
+            }
+        }
+        _ => {
+            for i in start..sorted.len() {
+                chosen.push(i);
+                find_all_sorted(sorted, k - 1, target - sorted[i], i + 1, chosen, found);
+                chosen.pop();
+            }
+        }
+    }
+}
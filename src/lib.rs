@@ -0,0 +1,42 @@
+#![feature(bool_to_option)]
+#![feature(slice_fill)]
+#![feature(str_split_once)]
+#![feature(try_find)]
+#![feature(generic_associated_types)]
+
+//! Solutions to Advent of Code, one module per year, along with the pieces needed to run them:
+//! the day registry, inputs, known answers and phase timings.
+
+pub mod answers;
+pub mod aoc;
+pub mod bench;
+pub mod error;
+pub mod generate;
+pub mod grid;
+pub mod history;
+pub mod input;
+pub mod ksum;
+pub mod metrics;
+pub mod output;
+pub mod runner;
+pub mod scaffold;
+pub mod solution;
+pub mod validate;
+pub mod watch;
+
+use error::{Error, Result};
+use generate::Rng;
+use output::Table;
+use solution::Solution;
+
+/// Solves a day from the full text of its puzzle input
+pub type DayRunner = fn(&str) -> Result<Solution>;
+
+/// Checks a day's puzzle input without solving it, returning every problem found
+pub type Validator = fn(&str) -> Vec<Error>;
+
+/// Produces a random puzzle input, `size` scaling it in a way that depends on the day
+pub type Generator = fn(&mut Rng, Option<usize>) -> String;
+
+/// Explains how a day treats each piece of its input, for auditing the solver on odd inputs
+pub type Reporter = fn(&str) -> Result<Table>;
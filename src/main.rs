@@ -1,55 +1,611 @@
-#![feature(bool_to_option)]
-#![feature(slice_fill)]
-#![feature(str_split_once)]
-#![feature(try_find)]
-#![feature(generic_associated_types)]
+use std::collections::HashMap;
+use std::fs;
+use std::iter;
+use std::panic;
+use std::path::{Path, PathBuf};
+use std::process;
+use std::thread;
+use std::time::{Duration, Instant};
 
-use std::fs::File;
-use std::{io::BufReader, path::Path};
+use advent_of_code::answers::{Answers, Verdict};
+use advent_of_code::aoc::y2020::aoc3;
+use advent_of_code::aoc::*;
+use advent_of_code::bench;
+use advent_of_code::error::{Error, Result};
+use advent_of_code::generate::Rng;
+use advent_of_code::history::History;
+use advent_of_code::input::{self, Input};
+use advent_of_code::metrics;
+use advent_of_code::output::{self, Format, Record, Status};
+use advent_of_code::runner::{self, DayResult, Job, Outcome};
+use advent_of_code::scaffold::Project;
+use advent_of_code::solution::{format_duration, format_metrics, Answer, Solution};
+use advent_of_code::watch::Watcher;
+use advent_of_code::DayRunner;
 
-mod aoc;
-use aoc::*;
+mod cli;
+use cli::{Command, Options, USAGE};
 
 fn main() {
-    // run(1, aoc_1);
-    // run(2, aoc_2);
-    // run(3, aoc_3);
-    // run(4, aoc_4);
-    // run(5, aoc_5);
-    // run(6, aoc_6);
-    // run(7, aoc_7);
-    // run(8, aoc_8);
-    // run(9, aoc_9);
-    // run(10, aoc_10);
-    // run(11, aoc_11);
-    // run(12, aoc_12);
-    // run(13, aoc_13);
-    // run(14, aoc_14);
-    // run(15, aoc_15);
-    // run(16, aoc_16);
-    run(17, aoc_17);
+    let args: Vec<String> = std::env::args().skip(1).collect();
+
+    let command = match Command::parse(&args) {
+        Ok(command) => command,
+        Err(err) => {
+            eprintln!("{}\n\n{}", err, USAGE);
+            process::exit(2);
+        }
+    };
+
+    let failed = match command {
+        Command::Help => {
+            println!("{}", USAGE);
+            0
+        }
+        Command::List => {
+            list_days();
+            0
+        }
+        Command::Run(options) => {
+            metrics::enable(options.metrics);
+            run_days(&options)
+        }
+        Command::Check(options) => options
+            .days
+            .iter()
+            .filter(|&&day| !check_day(day, &options))
+            .count(),
+        Command::Watch(options) => {
+            metrics::enable(options.metrics);
+            watch_day(options.days[0], &options);
+            1
+        }
+        Command::Gen(options) => {
+            if generate_input(options.days[0], &options) {
+                0
+            } else {
+                1
+            }
+        }
+        Command::New(options) => {
+            if new_day(options.days[0], &options) {
+                0
+            } else {
+                1
+            }
+        }
+        Command::Report(options) => {
+            if report_day(options.days[0], &options) {
+                0
+            } else {
+                1
+            }
+        }
+        Command::Bench(options) => {
+            let mut history = load_history(&options);
+            options
+                .days
+                .iter()
+                .filter(|&&day| !bench_day(day, &options, history.as_mut()))
+                .count()
+        }
+    };
+
+    if failed > 0 {
+        eprintln!("{} days failed", failed);
+        process::exit(1);
+    }
+}
+
+fn load_answers(options: &Options) -> Answers {
+    let mut answers = Answers::default();
+    for path in &options.answers {
+        match Answers::load(path) {
+            Ok(loaded) => answers.extend(loaded),
+            Err(err) => {
+                eprintln!("Could not read answers: {}", err);
+                process::exit(2);
+            }
+        }
+    }
+    answers
+}
+
+fn load_history(options: &Options) -> Option<History> {
+    let path = options.history.as_ref()?;
+    match History::load(path) {
+        Ok(history) => Some(history),
+        Err(err) => {
+            eprintln!("Could not read benchmark history: {}", err);
+            process::exit(2);
+        }
+    }
+}
+
+/// Where the input of a day is read from, given the options
+fn source(day: DayId, options: &Options) -> Input {
+    match (&options.input, &options.input_set) {
+        (Some(arg), _) => Input::from_arg(arg),
+        (None, Some(set)) => Input::for_set(day, set),
+        (None, None) => Input::for_day(day),
+    }
+}
+
+/// Finds a day and reads its input
+fn read_day(day: DayId, options: &Options) -> Result<(&'static Day, String)> {
+    let found = match find_day(day) {
+        Some(found) => found,
+        None => return Err(Error::new("no solver for this day").for_day(day)),
+    };
+
+    let source = source(day, options);
+    match source.read() {
+        Ok(input) => Ok((found, input)),
+        Err(err) => Err(Error::new(format!("could not read {}: {}", source, err)).for_day(day)),
+    }
+}
+
+/// Finds the runner of a day and reads its input, rejecting input that does not pass validation
+fn prepare_day(day: DayId, options: &Options) -> Result<(DayRunner, String)> {
+    let (found, input) = read_day(day, options)?;
+    let problems = (found.validator)(&input);
+    let mut problems = problems.into_iter();
+    match (problems.next(), problems.len()) {
+        (None, _) => Ok((found.runner, input)),
+        (Some(first), 0) => Err(first.for_day(day)),
+        (Some(first), more) => Err(Error::new(format!(
+            "invalid input, {} and {} more problems (run 'aoc check {} {}' to see them all)",
+            first, more, day.year, day.day
+        ))
+        .for_day(day)),
+    }
+}
+
+/// Validates the input of a day and prints every problem found, returning whether there were none
+fn check_day(day: DayId, options: &Options) -> bool {
+    println!("AOC {}", day);
+    let problems = match read_day(day, options) {
+        Ok((found, input)) => (found.validator)(&input),
+        Err(err) => vec![err],
+    };
+
+    if problems.is_empty() {
+        println!("\tOK");
+    }
+    for problem in &problems {
+        println!("\t{}", problem);
+    }
+    problems.is_empty()
+}
+
+/// Prints a random input for a day, the seed going to stderr so the input can be piped into a run
+fn generate_input(day: DayId, options: &Options) -> bool {
+    let generator = match find_day(day).and_then(|found| found.generator) {
+        Some(generator) => generator,
+        None => {
+            eprintln!("No input generator for {}", day);
+            return false;
+        }
+    };
+
+    let seed = options.seed.unwrap_or_else(Rng::seed_from_time);
+    eprintln!("Generating {} with seed {}", day, seed);
+    println!("{}", generator(&mut Rng::new(seed), options.size));
+    true
+}
+
+/// Prints the report of a day on its input, returning false if there is none
+fn report_day(day: DayId, options: &Options) -> bool {
+    let table = read_day(day, options).and_then(|(found, input)| match found.reporter {
+        Some(reporter) => reporter(&input).map_err(|err| err.for_day(day)),
+        None => Err(Error::new("no report for this day").for_day(day)),
+    });
+
+    match table {
+        Ok(table) => {
+            match options.format {
+                Format::Text => println!("{}", table.to_text()),
+                Format::Json => println!("{}", table.to_json()),
+                Format::Csv => println!("{}", table.to_csv()),
+            }
+            true
+        }
+        Err(err) => {
+            eprintln!("{}", err);
+            false
+        }
+    }
+}
+
+/// Adds the files of a new day to the project in the current directory
+fn new_day(day: DayId, options: &Options) -> bool {
+    let title = options
+        .title
+        .clone()
+        .unwrap_or_else(|| format!("Day {}", day.day));
+    match Project::new(".", input::root()).new_day(day, &title) {
+        Ok(written) => {
+            println!("Added {}", day);
+            for path in written {
+                println!("\t{}", path.display());
+            }
+            true
+        }
+        Err(err) => {
+            eprintln!("Could not add {}: {}", day, err);
+            false
+        }
+    }
+}
+
+/// How often watched files are checked for changes
+const WATCH_INTERVAL: Duration = Duration::from_millis(500);
+
+/// Runs a day on its input and examples, then again on each of them whenever it changes.
+/// Only returns if there is nothing to watch.
+fn watch_day(day: DayId, options: &Options) {
+    let found = match find_day(day) {
+        Some(found) => found,
+        None => {
+            eprintln!("{}", Error::new("no solver for this day").for_day(day));
+            return;
+        }
+    };
+    let answers = load_answers(options);
+
+    // Examples are only watched along with the personal input, which the answers belong to as well
+    let sources = if options.input.is_none() && options.input_set.is_none() {
+        iter::once(Input::for_day(day))
+            .chain(Input::examples(day))
+            .collect()
+    } else {
+        vec![source(day, options)]
+    };
+    let paths: Vec<PathBuf> = sources
+        .into_iter()
+        .filter_map(|source| match source {
+            Input::File(path) => Some(path),
+            _ => None,
+        })
+        .collect();
+    if paths.is_empty() {
+        eprintln!("Only files can be watched");
+        return;
+    }
+
+    // The answers only apply to the first file, the examples have none
+    let mut last = HashMap::new();
+    for path in &paths {
+        let answers = (path == &paths[0]).then_some(&answers);
+        rerun_day(day, found, path, answers, &mut last);
+    }
+
+    let mut watcher = Watcher::new(paths.clone());
+    loop {
+        thread::sleep(WATCH_INTERVAL);
+        for path in watcher.changed() {
+            let answers = (path == paths[0]).then_some(&answers);
+            rerun_day(day, found, &path, answers, &mut last);
+        }
+    }
 }
 
-fn load_file(path: &str) -> BufReader<File> {
-    let path = Path::new(path);
-    let file = File::open(path).unwrap();
-    let reader = BufReader::new(file);
-    reader
+/// Runs a day on one watched file and prints its answers next to those of the last run on that file
+fn rerun_day(
+    day: DayId,
+    found: &Day,
+    path: &Path,
+    answers: Option<&Answers>,
+    last: &mut HashMap<PathBuf, [Answer; 2]>,
+) {
+    println!("AOC {}, {}", day, path.display());
+    let input = match fs::read_to_string(path) {
+        Ok(input) => input,
+        Err(err) => return println!("\tcould not read {}: {}", path.display(), err),
+    };
+
+    let problems = (found.validator)(&input);
+    if !problems.is_empty() {
+        for problem in problems {
+            println!("\t{}", problem);
+        }
+        return;
+    }
+
+    let solution = match runner::run(day, found.runner, &input).outcome {
+        Outcome::Solved(solution) => solution,
+        Outcome::Failed(err) => return println!("\t{}", err),
+        Outcome::Panicked => return println!("\tpanicked"),
+    };
+    let verdicts = match answers {
+        Some(answers) => answers.verify(day, &solution),
+        None => [Verdict::Unknown, Verdict::Unknown],
+    };
+
+    let previous = last.get(path);
+    let parts = [
+        (
+            "Part 1",
+            &solution.part1,
+            solution.timings.part1,
+            &verdicts[0],
+        ),
+        (
+            "Part 2",
+            &solution.part2,
+            solution.timings.part2,
+            &verdicts[1],
+        ),
+    ];
+    for (i, (name, answer, time, verdict)) in parts.iter().enumerate() {
+        let time = time.map_or(String::new(), |time| {
+            format!(" (~{})", format_duration(time))
+        });
+        let change = match previous.map(|previous| &previous[i]) {
+            Some(before) if before != *answer => format!(", was {}", before),
+            _ => String::new(),
+        };
+        println!("\t{}{}: {} [{}]{}", name, time, answer, verdict, change);
+    }
+    if !solution.metrics.is_empty() {
+        println!("\tMetrics: {}", format_metrics(&solution.metrics));
+    }
+
+    last.insert(path.to_path_buf(), [solution.part1, solution.part2]);
+}
+
+fn list_days() {
+    for (i, year) in YEARS.iter().enumerate() {
+        if i > 0 {
+            println!();
+        }
+        println!("{}", year.year);
+
+        for day in year.days {
+            let sets = Input::sets(DayId::new(year.year, day.day));
+            if sets.is_empty() {
+                println!("{:>2}  {}", day.day, day.title);
+            } else {
+                println!("{:>2}  {:<25} {}", day.day, day.title, sets.join(", "));
+            }
+        }
+
+        let missing: Vec<String> = missing_days(year.year).map(|day| day.to_string()).collect();
+        if !missing.is_empty() {
+            println!("Missing: {}", missing.join(", "));
+        }
+    }
+}
+
+/// Runs the selected days and prints their results, returning the number of days that failed.
+/// Text is printed as each day finishes, unless the days run in parallel.
+fn run_days(options: &Options) -> usize {
+    let answers = load_answers(options);
+    let to_record = |result: DayResult| {
+        let verdicts = match &result.outcome {
+            Outcome::Solved(solution) => answers.verify(result.day, solution),
+            _ => [Verdict::Unknown, Verdict::Unknown],
+        };
+        Record { result, verdicts }
+    };
+
+    let sw = Instant::now();
+    let records: Vec<Record> = if options.parallel {
+        run_parallel(options).into_iter().map(to_record).collect()
+    } else {
+        let print = options.format == Format::Text;
+        options
+            .days
+            .iter()
+            .map(|&day| to_record(run_day(day, options)))
+            .inspect(|record| {
+                if print {
+                    print_record(record)
+                }
+            })
+            .collect()
+    };
+    let elapsed = sw.elapsed();
+
+    match options.format {
+        Format::Text if options.parallel => print_table(&records, elapsed),
+        Format::Text => (),
+        Format::Json => println!("{}", output::to_json(&records)),
+        Format::Csv => println!("{}", output::to_csv(&records)),
+    }
+
+    records.iter().filter(|r| r.status().is_failure()).count()
+}
+
+fn run_day(day: DayId, options: &Options) -> DayResult {
+    match (prepare_day(day, options), &options.slopes) {
+        // Only given for 2020 day 3, whose part 2 multiplies the trees of every slope
+        (Ok((_, input)), Some(slopes)) => {
+            runner::run(day, |input| aoc3::solve(input, slopes), &input)
+        }
+        (Ok((runner, input)), None) => runner::run(day, runner, &input),
+        (Err(err), _) => DayResult::failed(day, err),
+    }
+}
+
+/// Runs every day on a thread pool, returning the results in the order of the days
+fn run_parallel(options: &Options) -> Vec<DayResult> {
+    let mut prepared = Vec::new();
+    let mut jobs = Vec::new();
+    for &day in &options.days {
+        match prepare_day(day, options) {
+            Ok((runner, input)) => {
+                jobs.push(Job { day, runner, input });
+                prepared.push(Ok(day));
+            }
+            Err(err) => prepared.push(Err(DayResult::failed(day, err))),
+        }
+    }
+
+    let mut results = runner::run_parallel(jobs, runner::default_workers()).into_iter();
+    prepared
+        .into_iter()
+        .map(|prepared| match prepared {
+            Ok(_) => results.next().expect("a result for every job"),
+            Err(failed) => failed,
+        })
+        .collect()
+}
+
+fn print_record(record: &Record) {
+    let day = record.result.day;
+    match &record.result.outcome {
+        Outcome::Solved(solution) => print_solution(day, solution, &record.verdicts),
+        Outcome::Failed(err) => eprintln!("AOC {}\n\t{}", day, err),
+        Outcome::Panicked => eprintln!("AOC {}\n\tFailed", day),
+    }
 }
 
-fn run(day: u32, runner: DayRunner) {
-    let result = runner.run(format!("./inputs/aoc_{}_input.txt", day).as_str());
-    println!("AOC {}\n\t{}", day, result);
+/// Prints a row per day with the wall time of the whole run as the total
+fn print_table(records: &[Record], elapsed: Duration) {
+    println!(
+        "{:>4}  {:>3}  {:>16}  {:>16}  {:<8}{:>10}",
+        "Year", "Day", "Part 1", "Part 2", "Status", "Time"
+    );
+
+    let mut errors = Vec::new();
+    for record in records {
+        let result = &record.result;
+        let (part1, part2) = match &result.outcome {
+            Outcome::Solved(solution) => (solution.part1.to_string(), solution.part2.to_string()),
+            Outcome::Failed(err) => {
+                errors.push(err.to_string());
+                ("-".to_string(), "-".to_string())
+            }
+            Outcome::Panicked => {
+                errors.push(format!("{}: panicked", result.day));
+                ("-".to_string(), "-".to_string())
+            }
+        };
+
+        println!(
+            "{:>4}  {:>3}  {:>16}  {:>16}  {:<8}{:>10}",
+            result.day.year,
+            result.day.day,
+            part1,
+            part2,
+            record.status().to_string(),
+            format_duration(result.wall_time)
+        );
+    }
+
+    let passed = records
+        .iter()
+        .filter(|r| r.status() == Status::Pass)
+        .count();
+    let total: Duration = records.iter().map(|r| r.result.wall_time).sum();
+    println!(
+        "{:>4}  {:>3}  {:>16}  {:>16}  {:<8}{:>10}",
+        "",
+        "",
+        "",
+        "",
+        format!("{}/{}", passed, records.len()),
+        format_duration(elapsed)
+    );
+    println!("\nSum of day times: {}", format_duration(total));
+
+    for err in errors {
+        eprintln!("{}", err);
+    }
 }
 
-type DayRunner = fn(BufReader<File>) -> String;
+/// Benchmarks a single day and prints its timings, returning false if it failed.
+/// Regressions against the history count as failures.
+fn bench_day(day: DayId, options: &Options, history: Option<&mut History>) -> bool {
+    let (runner, input) = match prepare_day(day, options) {
+        Ok(prepared) => prepared,
+        Err(err) => {
+            eprintln!("AOC {}\n\t{}", day, err);
+            return false;
+        }
+    };
+
+    // A single checked run up front, so the measured runs can ignore the result
+    let result = panic::catch_unwind(|| -> Result<bench::BenchResult> {
+        runner(&input)?;
+        Ok(bench::bench(options.bench_time, || {
+            let _ = runner(&input);
+        }))
+    });
+
+    match result {
+        Ok(Ok(result)) => {
+            let history = match history {
+                Some(history) => history,
+                None => {
+                    result.print(day, &[]);
+                    return true;
+                }
+            };
 
-pub trait Runner {
-    fn run(self, file: &str) -> String;
+            let changes = history.compare(day, &result);
+            result.print(day, &changes);
+            if let Err(err) = history.record(day, &result) {
+                eprintln!("\tCould not record benchmark: {}", err);
+            }
+
+            let regressions: Vec<_> = changes
+                .iter()
+                .filter(|change| change.is_regression(options.threshold))
+                .collect();
+            for change in &regressions {
+                eprintln!(
+                    "\tRegression: {} median went from {} to {} ({:+.1}%, threshold {}%)",
+                    change.phase,
+                    format_duration(change.baseline),
+                    format_duration(change.current),
+                    change.ratio() * 100.0,
+                    options.threshold * 100.0
+                );
+            }
+            regressions.is_empty()
+        }
+        Ok(Err(err)) => {
+            eprintln!("AOC {}\n\t{}", day, err.for_day(day));
+            false
+        }
+        Err(_) => {
+            eprintln!("AOC {}\n\tFailed", day);
+            false
+        }
+    }
 }
 
-impl Runner for DayRunner {
-    fn run(self, file: &str) -> String {
-        (self)(load_file(file))
+fn print_solution(day: DayId, solution: &Solution, verdicts: &[Verdict; 2]) {
+    println!("AOC {}", day);
+
+    let timings = &solution.timings;
+    let parts = [
+        ("Part 1", &solution.part1, timings.part1, &verdicts[0]),
+        ("Part 2", &solution.part2, timings.part2, &verdicts[1]),
+    ];
+    for (name, answer, time, verdict) in parts.iter() {
+        match (answer, time) {
+            (Answer::Unsolved, _) => println!("\t{}: unsolved", name),
+            (_, Some(time)) => println!(
+                "\t{} (~{}): {} [{}]",
+                name,
+                format_duration(*time),
+                answer,
+                verdict
+            ),
+            (_, None) => println!("\t{}: {} [{}]", name, answer, verdict),
+        }
     }
-}
\ No newline at end of file
+
+    if let Some(time) = timings.parse {
+        println!("\tParse: ~{}", format_duration(time));
+    }
+    if !solution.metrics.is_empty() {
+        println!("\tMetrics: {}", format_metrics(&solution.metrics));
+    }
+    for note in &solution.notes {
+        println!("\t{}", note);
+    }
+}
@@ -0,0 +1,52 @@
+//! Counters solvers bump to show what they did beyond how long it took,
+//! such as the instructions a program executed or the nodes a search expanded.
+//! Counting is off unless enabled, leaving a single check in the solvers' hot loops.
+
+use std::cell::RefCell;
+use std::sync::atomic::{AtomicBool, Ordering};
+
+use crate::solution::Metrics;
+
+static ENABLED: AtomicBool = AtomicBool::new(false);
+
+thread_local! {
+    static COUNTERS: RefCell<Metrics> = const { RefCell::new(Vec::new()) };
+}
+
+/// Turns counting on or off for every thread
+pub fn enable(enabled: bool) {
+    ENABLED.store(enabled, Ordering::Relaxed);
+}
+
+pub fn is_enabled() -> bool {
+    ENABLED.load(Ordering::Relaxed)
+}
+
+/// Adds `n` to the counter called `name` on this thread, if counting is enabled.
+/// Counters are reported in the order they were first counted.
+#[inline]
+pub fn count(name: &'static str, n: u64) {
+    if is_enabled() {
+        add(name, n);
+    }
+}
+
+fn add(name: &'static str, n: u64) {
+    COUNTERS.with(|counters| {
+        let mut counters = counters.borrow_mut();
+        match counters.iter_mut().find(|(counter, _)| *counter == name) {
+            Some((_, value)) => *value += n,
+            None => counters.push((name, n)),
+        }
+    });
+}
+
+/// Clears the counters of this thread
+pub fn reset() {
+    COUNTERS.with(|counters| counters.borrow_mut().clear());
+}
+
+/// Takes the counters of this thread since the last reset
+pub fn take() -> Metrics {
+    COUNTERS.with(|counters| counters.replace(Vec::new()))
+}
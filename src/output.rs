@@ -0,0 +1,348 @@
+use std::fmt::{self, Write};
+use std::str::FromStr;
+use std::time::Duration;
+
+use crate::answers::Verdict;
+use crate::runner::{DayResult, Outcome};
+use crate::solution::Answer;
+
+/// How the results of a run are printed
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Format {
+    Text,
+    Json,
+    Csv,
+}
+
+impl FromStr for Format {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Format, String> {
+        match s {
+            "text" => Ok(Format::Text),
+            "json" => Ok(Format::Json),
+            "csv" => Ok(Format::Csv),
+            _ => Err(format!("Unknown format '{}'", s)),
+        }
+    }
+}
+
+/// How a day did overall
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Status {
+    Pass,
+    Fail,
+    Unknown,
+    Error,
+    Panic,
+}
+
+impl Status {
+    pub fn is_failure(self) -> bool {
+        matches!(self, Status::Fail | Status::Error | Status::Panic)
+    }
+
+    fn as_str(self) -> &'static str {
+        match self {
+            Status::Pass => "pass",
+            Status::Fail => "fail",
+            Status::Unknown => "unknown",
+            Status::Error => "error",
+            Status::Panic => "panic",
+        }
+    }
+}
+
+impl fmt::Display for Status {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.as_str().to_uppercase())
+    }
+}
+
+/// The result of running a day along with how its answers compare to the known ones
+pub struct Record {
+    pub result: DayResult,
+    pub verdicts: [Verdict; 2],
+}
+
+impl Record {
+    /// Unknown or unsolved parts do not count against a day, a single wrong one fails it
+    pub fn status(&self) -> Status {
+        match self.result.outcome {
+            Outcome::Solved(_) => {
+                if self
+                    .verdicts
+                    .iter()
+                    .any(|v| matches!(v, Verdict::Fail { .. }))
+                {
+                    Status::Fail
+                } else if self.verdicts.contains(&Verdict::Pass) {
+                    Status::Pass
+                } else {
+                    Status::Unknown
+                }
+            }
+            Outcome::Failed(_) => Status::Error,
+            Outcome::Panicked => Status::Panic,
+        }
+    }
+
+    fn error(&self) -> Option<String> {
+        match &self.result.outcome {
+            Outcome::Solved(_) => None,
+            Outcome::Failed(err) => Some(err.to_string()),
+            Outcome::Panicked => Some("panicked".to_string()),
+        }
+    }
+
+    fn metrics(&self) -> &[(&'static str, u64)] {
+        match &self.result.outcome {
+            Outcome::Solved(solution) => &solution.metrics,
+            _ => &[],
+        }
+    }
+
+    fn parse_time(&self) -> Option<Duration> {
+        match &self.result.outcome {
+            Outcome::Solved(solution) => solution.timings.parse,
+            _ => None,
+        }
+    }
+}
+
+/// Formats the records as a JSON array with one object per day, timings in nanoseconds
+pub fn to_json(records: &[Record]) -> String {
+    let mut out = String::from("[");
+    for (i, record) in records.iter().enumerate() {
+        if i > 0 {
+            out.push(',');
+        }
+
+        let id = record.result.day;
+        write!(out, "\n  {{\"year\": {}, \"day\": {}", id.year, id.day).unwrap();
+        write!(out, ", \"status\": \"{}\"", record.status().as_str()).unwrap();
+        for (name, (answer, time), verdict) in part_fields(record) {
+            write!(out, ", \"{}\": {{\"answer\": ", name).unwrap();
+            match answer {
+                None | Some(Answer::Unsolved) => out.push_str("null"),
+                Some(Answer::Number(val)) => write!(out, "{}", val).unwrap(),
+                Some(Answer::Text(val)) => out.push_str(&json_string(val)),
+            }
+            write!(out, ", \"time_ns\": {}", json_nanos(time)).unwrap();
+            write!(out, ", \"verdict\": \"{}\"", verdict_str(verdict)).unwrap();
+            if let Verdict::Fail { expected } = verdict {
+                write!(
+                    out,
+                    ", \"expected\": {}",
+                    json_string(&expected.to_string())
+                )
+                .unwrap();
+            }
+            out.push('}');
+        }
+        write!(out, ", \"parse_ns\": {}", json_nanos(record.parse_time())).unwrap();
+        write!(
+            out,
+            ", \"wall_ns\": {}",
+            json_nanos(Some(record.result.wall_time))
+        )
+        .unwrap();
+        out.push_str(", \"metrics\": {");
+        for (i, (name, value)) in record.metrics().iter().enumerate() {
+            let separator = if i > 0 { ", " } else { "" };
+            write!(out, "{}{}: {}", separator, json_string(name), value).unwrap();
+        }
+        out.push('}');
+        match record.error() {
+            Some(err) => write!(out, ", \"error\": {}}}", json_string(&err)).unwrap(),
+            None => out.push_str(", \"error\": null}"),
+        }
+    }
+    out.push_str("\n]");
+    out
+}
+
+const CSV_HEADER: &str =
+    "year,day,status,part1,part1_ns,part1_verdict,part2,part2_ns,part2_verdict,parse_ns,wall_ns,metrics,error";
+
+/// Formats the records as CSV with a header row, timings in nanoseconds and empty fields for missing values
+pub fn to_csv(records: &[Record]) -> String {
+    let mut out = String::from(CSV_HEADER);
+    for record in records {
+        let id = record.result.day;
+        write!(out, "\n{},{},{}", id.year, id.day, record.status().as_str()).unwrap();
+        for (_, (answer, time), verdict) in part_fields(record) {
+            let answer = match answer {
+                None | Some(Answer::Unsolved) => String::new(),
+                Some(answer) => answer.to_string(),
+            };
+            write!(
+                out,
+                ",{},{},{}",
+                csv_field(&answer),
+                csv_nanos(time),
+                verdict_str(verdict)
+            )
+            .unwrap();
+        }
+        write!(
+            out,
+            ",{},{},{},{}",
+            csv_nanos(record.parse_time()),
+            csv_nanos(Some(record.result.wall_time)),
+            csv_field(&csv_metrics(record.metrics())),
+            csv_field(&record.error().unwrap_or_default())
+        )
+        .unwrap();
+    }
+    out
+}
+
+/// Rows of text under named columns, such as the report of a day on each line of its input
+#[derive(Debug, Clone, Default)]
+pub struct Table {
+    pub columns: Vec<&'static str>,
+    pub rows: Vec<Vec<String>>,
+}
+
+impl Table {
+    pub fn new(columns: Vec<&'static str>) -> Table {
+        Table {
+            columns,
+            rows: Vec::new(),
+        }
+    }
+
+    /// Adds a row, which should have a value for every column
+    pub fn push(&mut self, row: Vec<String>) {
+        debug_assert_eq!(row.len(), self.columns.len());
+        self.rows.push(row);
+    }
+
+    /// Formats the table with its columns aligned, for reading in a terminal
+    pub fn to_text(&self) -> String {
+        let mut widths: Vec<usize> = self.columns.iter().map(|c| c.chars().count()).collect();
+        for row in &self.rows {
+            for (width, value) in widths.iter_mut().zip(row) {
+                *width = (*width).max(value.chars().count());
+            }
+        }
+
+        let line = |values: Vec<&str>| {
+            let cells: Vec<String> = values
+                .iter()
+                .zip(&widths)
+                .map(|(value, &width)| format!("{:<width$}", value, width = width))
+                .collect();
+            cells.join("  ").trim_end().to_string()
+        };
+        let mut lines = vec![line(self.columns.clone())];
+        lines.extend(
+            self.rows
+                .iter()
+                .map(|row| line(row.iter().map(String::as_str).collect())),
+        );
+        lines.join("\n")
+    }
+
+    /// Formats the table as CSV with a header row
+    pub fn to_csv(&self) -> String {
+        let mut out = self.columns.join(",");
+        for row in &self.rows {
+            let fields: Vec<String> = row.iter().map(|value| csv_field(value)).collect();
+            write!(out, "\n{}", fields.join(",")).unwrap();
+        }
+        out
+    }
+
+    /// Formats the table as a JSON array with an object per row, every value a string
+    pub fn to_json(&self) -> String {
+        let mut out = String::from("[");
+        for (i, row) in self.rows.iter().enumerate() {
+            if i > 0 {
+                out.push(',');
+            }
+            let fields: Vec<String> = self
+                .columns
+                .iter()
+                .zip(row)
+                .map(|(column, value)| format!("{}: {}", json_string(column), json_string(value)))
+                .collect();
+            write!(out, "\n  {{{}}}", fields.join(", ")).unwrap();
+        }
+        out.push_str("\n]");
+        out
+    }
+}
+
+type PartFields<'a> = (
+    &'static str,
+    (Option<&'a Answer>, Option<Duration>),
+    &'a Verdict,
+);
+
+/// Each part's name, answer, time and verdict, where the answer and time are missing if the day failed
+fn part_fields(record: &Record) -> Vec<PartFields<'_>> {
+    let (part1, part2) = match &record.result.outcome {
+        Outcome::Solved(solution) => (
+            (Some(&solution.part1), solution.timings.part1),
+            (Some(&solution.part2), solution.timings.part2),
+        ),
+        _ => ((None, None), (None, None)),
+    };
+
+    vec![
+        ("part1", part1, &record.verdicts[0]),
+        ("part2", part2, &record.verdicts[1]),
+    ]
+}
+
+fn verdict_str(verdict: &Verdict) -> &'static str {
+    match verdict {
+        Verdict::Pass => "pass",
+        Verdict::Fail { .. } => "fail",
+        Verdict::Unknown => "unknown",
+    }
+}
+
+fn json_nanos(time: Option<Duration>) -> String {
+    time.map_or("null".to_string(), |time| time.as_nanos().to_string())
+}
+
+fn csv_nanos(time: Option<Duration>) -> String {
+    time.map_or(String::new(), |time| time.as_nanos().to_string())
+}
+
+fn json_string(text: &str) -> String {
+    let mut out = String::from("\"");
+    for c in text.chars() {
+        match c {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            '\t' => out.push_str("\\t"),
+            c if c.is_control() => write!(out, "\\u{:04x}", c as u32).unwrap(),
+            c => out.push(c),
+        }
+    }
+    out.push('"');
+    out
+}
+
+/// Metrics in a single field, e.g. `instructions=2148;nodes_expanded=12`
+fn csv_metrics(metrics: &[(&'static str, u64)]) -> String {
+    metrics
+        .iter()
+        .map(|(name, value)| format!("{}={}", name, value))
+        .collect::<Vec<_>>()
+        .join(";")
+}
+
+/// Quotes a field when it holds a separator, quote or line break
+fn csv_field(text: &str) -> String {
+    if text.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", text.replace('"', "\"\""))
+    } else {
+        text.to_string()
+    }
+}
@@ -0,0 +1,113 @@
+use std::panic;
+use std::thread;
+use std::time::{Duration, Instant};
+
+use parking_lot::Mutex;
+
+use crate::aoc::DayId;
+use crate::bench;
+use crate::error::{Error, Result};
+use crate::metrics;
+use crate::solution::Solution;
+use crate::DayRunner;
+
+/// A day waiting to be run along with its input
+pub struct Job {
+    pub day: DayId,
+    pub runner: DayRunner,
+    pub input: String,
+}
+
+/// What came of running a single day
+pub enum Outcome {
+    Solved(Solution),
+    Failed(Error),
+    Panicked,
+}
+
+pub struct DayResult {
+    pub day: DayId,
+    pub outcome: Outcome,
+    pub wall_time: Duration,
+}
+
+impl DayResult {
+    /// A day that failed before its solver could run, such as when its input is missing
+    pub fn failed(day: DayId, err: Error) -> DayResult {
+        DayResult {
+            day,
+            outcome: Outcome::Failed(err.for_day(day)),
+            wall_time: Duration::ZERO,
+        }
+    }
+}
+
+/// Runs a solver once, recording the timings of its phases and anything it counted.
+/// Bad input is reported as an error, panics are caught so a failing day cannot take the others down.
+/// The solver is usually the runner of the day, but may be any function solving it.
+pub fn run(
+    day: DayId,
+    runner: impl Fn(&str) -> Result<Solution> + panic::RefUnwindSafe,
+    input: &str,
+) -> DayResult {
+    let sw = Instant::now();
+    let result = panic::catch_unwind(|| {
+        bench::reset();
+        metrics::reset();
+        let solution = runner(input);
+        (solution, bench::take(), metrics::take())
+    });
+    let wall_time = sw.elapsed();
+
+    let outcome = match result {
+        Ok((Ok(mut solution), timings, metrics)) => {
+            solution.timings = timings;
+            solution.metrics = metrics;
+            Outcome::Solved(solution)
+        }
+        Ok((Err(err), _, _)) => Outcome::Failed(err.for_day(day)),
+        Err(_) => Outcome::Panicked,
+    };
+
+    DayResult {
+        day,
+        outcome,
+        wall_time,
+    }
+}
+
+/// Runs every job on a pool of `workers` threads, returning the results in the order of `jobs`
+pub fn run_parallel(jobs: Vec<Job>, workers: usize) -> Vec<DayResult> {
+    let count = jobs.len();
+    let queue = Mutex::new(jobs.into_iter().enumerate());
+    let results = Mutex::new((0..count).map(|_| None).collect::<Vec<_>>());
+
+    thread::scope(|scope| {
+        for _ in 0..workers.clamp(1, count.max(1)) {
+            scope.spawn(|| loop {
+                // The lock is only held to take the next job, not while running it
+                let next = queue.lock().next();
+                let (index, job) = match next {
+                    Some(next) => next,
+                    None => break,
+                };
+
+                let result = run(job.day, job.runner, &job.input);
+                results.lock()[index] = Some(result);
+            });
+        }
+    });
+
+    results
+        .into_inner()
+        .into_iter()
+        .map(|result| result.expect("every job is run by a worker"))
+        .collect()
+}
+
+/// The number of workers to use when none is given, one per available core
+pub fn default_workers() -> usize {
+    thread::available_parallelism()
+        .map(|n| n.get())
+        .unwrap_or(1)
+}
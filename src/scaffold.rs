@@ -0,0 +1,219 @@
+//! Starts a new day: a solver module that compiles but solves nothing, its entry in the registry,
+//! empty input and example files to paste into, and a failing example test to fill in.
+
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use crate::aoc::{DayId, LAST_DAY};
+
+const SOLVER: &str = "use crate::bench::{timed, Phase};
+use crate::error::{parse_lines, Error, Result};
+use crate::solution::{Answer, Solution};
+
+pub fn aoc_{day}(input: &str) -> Result<Solution> {
+    let lines = timed(Phase::Parse, || parse_lines(input.lines(), parse_line))?;
+
+    let part1 = timed(Phase::Part1, || part_1(&lines));
+    let part2 = timed(Phase::Part2, || part_2(&lines));
+
+    Ok(Solution::new(part1, part2))
+}
+
+/// Nothing is rejected until the input is known
+pub fn validate(_input: &str) -> Vec<Error> {
+    Vec::new()
+}
+
+fn parse_line(line: &str) -> Result<String> {
+    Ok(line.to_string())
+}
+
+fn part_1(_lines: &[String]) -> Answer {
+    Answer::Unsolved
+}
+
+fn part_2(_lines: &[String]) -> Answer {
+    Answer::Unsolved
+}
+";
+
+const TESTS: &str =
+    "//! Runs every day of {year} against the example inputs published with its puzzle,
+//! stored in `inputs/{year}/examples/`.
+
+use std::fs;
+
+use advent_of_code::aoc::{find_day, DayId};
+use advent_of_code::solution::{Answer, Solution};
+
+const YEAR: u16 = {year};
+
+fn example(day: u32, example: u32) -> String {
+    let path = format!(
+        \"inputs/{}/examples/aoc_{}_example{}.txt\",
+        YEAR, day, example
+    );
+    fs::read_to_string(&path).unwrap_or_else(|err| panic!(\"could not read {}: {}\", path, err))
+}
+
+fn solve(day: u32, example_number: u32) -> Solution {
+    let id = DayId::new(YEAR, day);
+    let runner = find_day(id).expect(\"day is registered\").runner;
+    runner(&example(day, example_number)).unwrap_or_else(|err| panic!(\"{}\", err.for_day(id)))
+}
+
+fn check(day: u32, example: u32, part1: impl Into<Answer>, part2: impl Into<Answer>) {
+    let solution = solve(day, example);
+    assert_eq!(solution.part1, part1.into(), \"day {} part 1\", day);
+    assert_eq!(solution.part2, part2.into(), \"day {} part 2\", day);
+}
+";
+
+const TEST: &str = "
+#[test]
+fn day_{day}() {
+    // Fails until the answers to the example are filled in and solved
+    check({day}, 1, 0, 0);
+}
+";
+
+/// Where a new day is added, the source tree of the project and the inputs directory
+pub struct Project {
+    pub root: PathBuf,
+    pub inputs: PathBuf,
+}
+
+impl Project {
+    pub fn new(root: impl Into<PathBuf>, inputs: impl Into<PathBuf>) -> Project {
+        Project {
+            root: root.into(),
+            inputs: inputs.into(),
+        }
+    }
+
+    /// Adds a day to a year that already has a module, returning every file created or changed.
+    /// Nothing is written unless the day is new, and input files that already exist are kept.
+    pub fn new_day(&self, id: DayId, title: &str) -> Result<Vec<PathBuf>, String> {
+        if !(1..=LAST_DAY).contains(&id.day) {
+            return Err(format!("{} is not a day of the event", id));
+        }
+
+        let year_dir = self.root.join(format!("src/aoc/y{}", id.year));
+        let year_mod = year_dir.join("mod.rs");
+        let registry = fs::read_to_string(&year_mod).map_err(|err| {
+            format!(
+                "could not read {}, each year needs its module first: {}",
+                year_mod.display(),
+                err
+            )
+        })?;
+
+        let solver = year_dir.join(format!("aoc{}.rs", id.day));
+        if solver.exists() {
+            return Err(format!("{} already exists", solver.display()));
+        }
+        let registry = register(&registry, id.day, title)
+            .ok_or_else(|| format!("{} is already registered or has no DAYS", id))?;
+
+        let tests = self.root.join(format!("tests/examples_{}.rs", id.year));
+        let mut test_file = match fs::read_to_string(&tests) {
+            Ok(content) => content,
+            Err(_) => fill(TESTS, id),
+        };
+        test_file.push_str(&fill(TEST, id));
+
+        let year_inputs = self.inputs.join(id.year.to_string());
+        let input = year_inputs.join(format!("aoc_{}_input.txt", id.day));
+        let example = year_inputs
+            .join("examples")
+            .join(format!("aoc_{}_example1.txt", id.day));
+
+        let mut written = vec![
+            write(&solver, &fill(SOLVER, id))?,
+            write(&year_mod, &registry)?,
+            write(&tests, &test_file)?,
+        ];
+        for path in [input, example] {
+            if !path.exists() {
+                written.push(write(&path, "")?);
+            }
+        }
+        Ok(written)
+    }
+}
+
+fn fill(template: &str, id: DayId) -> String {
+    template
+        .replace("{year}", &id.year.to_string())
+        .replace("{day}", &id.day.to_string())
+}
+
+fn write(path: &Path, content: &str) -> Result<PathBuf, String> {
+    let dir = path.parent().unwrap_or_else(|| Path::new("."));
+    fs::create_dir_all(dir)
+        .and_then(|_| fs::write(path, content))
+        .map_err(|err| format!("could not write {}: {}", path.display(), err))?;
+    Ok(path.to_path_buf())
+}
+
+/// Adds the module declaration and the `DAYS` entry of a day to the module of its year,
+/// keeping both in order of day. `None` if the day is already declared or there is no `DAYS`.
+fn register(registry: &str, day: u32, title: &str) -> Option<String> {
+    let declaration = format!("pub mod aoc{};", day);
+    let entry = format!(
+        "    Day::new({day}, {title:?}, aoc{day}::aoc_{day}, aoc{day}::validate),",
+        day = day,
+        title = title
+    );
+
+    let mut lines: Vec<String> = registry.lines().map(String::from).collect();
+    if lines.iter().any(|line| line.trim() == declaration) {
+        return None;
+    }
+
+    // Entries spanning several lines have their day on the line after `Day::new(`
+    let entry_day = |i: usize| {
+        let rest = lines[i].trim().strip_prefix("Day::new(")?;
+        let rest = if rest.is_empty() {
+            lines.get(i + 1)?.trim()
+        } else {
+            rest
+        };
+        rest.split(',').next()?.trim().parse::<u32>().ok()
+    };
+    let days_start = lines
+        .iter()
+        .position(|line| line.starts_with("pub const DAYS"))?;
+    let days_end = days_start + lines[days_start..].iter().position(|line| line == "];")?;
+    let entry_at = (days_start + 1..days_end)
+        .find(|&i| entry_day(i).is_some_and(|d| d > day))
+        .unwrap_or(days_end);
+    lines.insert(entry_at, entry);
+
+    let module_day = |line: &str| {
+        line.strip_prefix("pub mod aoc")?
+            .strip_suffix(';')?
+            .parse::<u32>()
+            .ok()
+    };
+    let modules: Vec<(usize, u32)> = lines
+        .iter()
+        .enumerate()
+        .filter_map(|(i, line)| Some((i, module_day(line)?)))
+        .collect();
+    // A year without days yet gets its first declaration above the doc comment of `DAYS`
+    let days_doc = (0..days_start)
+        .rev()
+        .take_while(|&i| lines[i].starts_with("///"))
+        .last()
+        .unwrap_or(days_start);
+    let declare_at = match modules.iter().find(|&&(_, d)| d > day) {
+        Some(&(i, _)) => i,
+        None => modules.last().map_or(days_doc, |&(i, _)| i + 1),
+    };
+    lines.insert(declare_at, declaration);
+
+    let mut registry = lines.join("\n");
+    registry.push('\n');
+    Some(registry)
+}
@@ -0,0 +1,116 @@
+use std::fmt;
+use std::time::Duration;
+
+/// The answer to one part of a puzzle
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Answer {
+    Unsolved,
+    Number(i128),
+    Text(String),
+}
+
+impl fmt::Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Answer::Unsolved => write!(f, "-"),
+            Answer::Number(val) => write!(f, "{}", val),
+            Answer::Text(val) => write!(f, "{}", val),
+        }
+    }
+}
+
+macro_rules! impl_answer_from_number {
+    ($($ty:ty),*) => {
+        $(
+            impl From<$ty> for Answer {
+                fn from(val: $ty) -> Self {
+                    Answer::Number(val as i128)
+                }
+            }
+        )*
+    };
+}
+
+impl_answer_from_number!(u16, u32, u64, usize, i16, i32, i64);
+
+impl From<String> for Answer {
+    fn from(val: String) -> Self {
+        Answer::Text(val)
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(val: &str) -> Self {
+        Answer::Text(val.to_string())
+    }
+}
+
+/// Time spent in each phase of a solver, where the solver measured it
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Timings {
+    pub parse: Option<Duration>,
+    pub part1: Option<Duration>,
+    pub part2: Option<Duration>,
+}
+
+/// What a solver counted while it ran, by counter name
+pub type Metrics = Vec<(&'static str, u64)>;
+
+#[derive(Debug, Clone)]
+pub struct Solution {
+    pub part1: Answer,
+    pub part2: Answer,
+    pub timings: Timings,
+    pub metrics: Metrics,
+    pub notes: Vec<String>,
+}
+
+impl Solution {
+    pub fn new(part1: impl Into<Answer>, part2: impl Into<Answer>) -> Solution {
+        Solution {
+            part1: part1.into(),
+            part2: part2.into(),
+            timings: Timings::default(),
+            metrics: Vec::new(),
+            notes: Vec::new(),
+        }
+    }
+
+    pub fn with_timings(mut self, timings: Timings) -> Solution {
+        self.timings = timings;
+        self
+    }
+
+    pub fn with_note(mut self, note: impl Into<String>) -> Solution {
+        self.notes.push(note.into());
+        self
+    }
+}
+
+/// Formats metrics as a list of counters, e.g. `instructions 2148, nodes_expanded 12`
+pub fn format_metrics(metrics: &Metrics) -> String {
+    metrics
+        .iter()
+        .map(|(name, value)| format!("{} {}", name, value))
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+/// Formats a duration with a unit picked from its magnitude, e.g. `850 ns`, `12.3 µs`, `4.07 ms`
+pub fn format_duration(duration: Duration) -> String {
+    let nanos = duration.as_nanos();
+    let (value, unit) = match nanos {
+        0..=999 => return format!("{} ns", nanos),
+        1_000..=999_999 => (nanos as f64 / 1e3, "µs"),
+        1_000_000..=999_999_999 => (nanos as f64 / 1e6, "ms"),
+        _ => (nanos as f64 / 1e9, "s"),
+    };
+
+    if value < 10.0 {
+        format!("{:.2} {}", value, unit)
+    } else if value < 100.0 {
+        format!("{:.1} {}", value, unit)
+    } else {
+        format!("{:.0} {}", value, unit)
+    }
+}
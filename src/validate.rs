@@ -0,0 +1,111 @@
+//! Helpers for the input validators of each day.
+//! Unlike parsing, validation keeps going after a problem so every broken line is reported at once.
+
+use std::str::FromStr;
+
+use crate::error::Error;
+
+/// The problems found in an input so far
+#[derive(Debug, Default)]
+pub struct Problems {
+    errors: Vec<Error>,
+}
+
+impl Problems {
+    pub fn new() -> Problems {
+        Problems::default()
+    }
+
+    /// Reports a problem with a whole line, `line` being 1-based
+    pub fn line(&mut self, line: usize, message: impl Into<String>, text: &str) {
+        self.errors
+            .push(Error::invalid(message, text).at_line(line));
+    }
+
+    /// Reports a problem with `part` of `text`, the full content of the line, pointing at the column it starts on
+    pub fn at(&mut self, line: usize, text: &str, part: &str, message: impl Into<String>) {
+        self.errors.push(
+            Error::invalid(message, part)
+                .at_line(line)
+                .at_column(column_of(text, part)),
+        );
+    }
+
+    /// Reports a problem with the input as a whole
+    pub fn input(&mut self, message: impl Into<String>) {
+        self.errors.push(Error::new(message));
+    }
+
+    /// Checks that `part` of `text` parses as a `T`, reporting it otherwise
+    pub fn value<T: FromStr>(&mut self, line: usize, text: &str, part: &str) -> Option<T> {
+        let value = part.parse().ok();
+        if value.is_none() {
+            self.at(line, text, part, "invalid value");
+        }
+        value
+    }
+
+    /// Checks that every character of `part` is one of `allowed`, reporting each that is not
+    pub fn chars(&mut self, line: usize, text: &str, part: &str, allowed: impl Fn(char) -> bool) {
+        let start = column_of(text, part);
+        for (i, c) in part.char_indices() {
+            if !allowed(c) {
+                self.errors.push(
+                    Error::invalid("unexpected character", &c.to_string())
+                        .at_line(line)
+                        .at_column(start + part[..i].chars().count()),
+                );
+            }
+        }
+    }
+
+    pub fn into_errors(self) -> Vec<Error> {
+        self.errors
+    }
+}
+
+/// The 1-based column `part` starts on, where `part` is a slice of `text`
+pub fn column_of(text: &str, part: &str) -> usize {
+    let offset = (part.as_ptr() as usize)
+        .checked_sub(text.as_ptr() as usize)
+        .filter(|&offset| offset <= text.len())
+        .unwrap_or(0);
+    text[..offset].chars().count() + 1
+}
+
+/// Checks every line is an unsigned number, failing on an empty input
+pub fn numbers(input: &str) -> Vec<Error> {
+    let mut problems = Problems::new();
+    if input.trim().is_empty() {
+        problems.input("empty input");
+    }
+    for (i, line) in input.lines().enumerate() {
+        problems.value::<u64>(i + 1, line, line);
+    }
+    problems.into_errors()
+}
+
+/// Checks the input is a non-empty rectangle of the `allowed` characters
+pub fn grid(input: &str, allowed: &str) -> Vec<Error> {
+    let mut problems = Problems::new();
+    let width = match input.lines().next() {
+        Some(line) if !line.is_empty() => line.chars().count(),
+        _ => {
+            problems.input("empty input");
+            return problems.into_errors();
+        }
+    };
+
+    for (i, line) in input.lines().enumerate() {
+        let len = line.chars().count();
+        if len != width {
+            problems.line(
+                i + 1,
+                format!("row is {} wide rather than {}", len, width),
+                line,
+            );
+        }
+        problems.chars(i + 1, line, line, |c| allowed.contains(c));
+    }
+    problems.into_errors()
+}
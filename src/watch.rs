@@ -0,0 +1,47 @@
+//! Polls files for changes, letting `aoc watch` re-run a day as its inputs are edited
+
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::time::SystemTime;
+
+/// What a file looked like when last polled, `None` if it did not exist
+type Stamp = Option<(SystemTime, u64)>;
+
+pub struct Watcher {
+    files: Vec<(PathBuf, Stamp)>,
+}
+
+impl Watcher {
+    /// Starts watching `paths`, which do not need to exist yet
+    pub fn new(paths: impl IntoIterator<Item = PathBuf>) -> Watcher {
+        Watcher {
+            files: paths
+                .into_iter()
+                .map(|path| {
+                    let stamp = stamp(&path);
+                    (path, stamp)
+                })
+                .collect(),
+        }
+    }
+
+    /// The files that were modified, created or removed since the last poll
+    pub fn changed(&mut self) -> Vec<PathBuf> {
+        let mut changed = Vec::new();
+        for (path, last) in &mut self.files {
+            let current = stamp(path);
+            if current != *last {
+                *last = current;
+                changed.push(path.clone());
+            }
+        }
+        changed
+    }
+}
+
+/// The size is checked along with the modification time,
+/// as some file systems only keep the latter to the second
+fn stamp(path: &Path) -> Stamp {
+    let metadata = fs::metadata(path).ok()?;
+    Some((metadata.modified().ok()?, metadata.len()))
+}
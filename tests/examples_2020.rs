@@ -0,0 +1,166 @@
+//! Runs every day of 2020 against the example inputs published with its puzzle,
+//! stored in `inputs/2020/examples/`.
+//! Parts that are not solved yet have their example answers in ignored tests.
+
+use std::fs;
+
+use advent_of_code::aoc::y2020::{aoc14, aoc5, aoc9};
+use advent_of_code::aoc::{find_day, DayId};
+use advent_of_code::solution::{Answer, Solution};
+
+const YEAR: u16 = 2020;
+
+fn example(day: u32, example: u32) -> String {
+    let path = format!(
+        "inputs/{}/examples/aoc_{}_example{}.txt",
+        YEAR, day, example
+    );
+    fs::read_to_string(&path).unwrap_or_else(|err| panic!("could not read {}: {}", path, err))
+}
+
+fn solve(day: u32, example_number: u32) -> Solution {
+    let id = DayId::new(YEAR, day);
+    let runner = find_day(id).expect("day is registered").runner;
+    runner(&example(day, example_number)).unwrap_or_else(|err| panic!("{}", err.for_day(id)))
+}
+
+fn check(day: u32, example: u32, part1: impl Into<Answer>, part2: impl Into<Answer>) {
+    let solution = solve(day, example);
+    assert_eq!(solution.part1, part1.into(), "day {} part 1", day);
+    assert_eq!(solution.part2, part2.into(), "day {} part 2", day);
+}
+
+#[test]
+fn day_1() {
+    check(1, 1, 514579, 241861950);
+}
+
+#[test]
+fn day_2() {
+    check(2, 1, 2, 1);
+}
+
+#[test]
+fn day_3() {
+    check(3, 1, 7, 336);
+}
+
+#[test]
+fn day_4() {
+    assert_eq!(solve(4, 1).part2, Answer::from(2));
+    assert_eq!(solve(4, 2).part2, Answer::from(0));
+    assert_eq!(solve(4, 3).part2, Answer::from(4));
+}
+
+#[test]
+#[ignore = "part 1 is not solved yet"]
+fn day_4_part_1() {
+    assert_eq!(solve(4, 1).part1, Answer::from(2));
+}
+
+#[test]
+fn day_5() {
+    let input = example(5, 1);
+    let seat_ids: Vec<u32> = input.lines().map(|l| aoc5::seat_id(l).unwrap()).collect();
+    assert_eq!(seat_ids, [357, 567, 119, 820]);
+
+    assert_eq!(solve(5, 1).part1, Answer::from(820));
+}
+
+#[test]
+fn day_6() {
+    assert_eq!(solve(6, 1).part2, Answer::from(6));
+}
+
+#[test]
+#[ignore = "part 1 is not solved yet"]
+fn day_6_part_1() {
+    assert_eq!(solve(6, 1).part1, Answer::from(11));
+}
+
+#[test]
+fn day_7() {
+    check(7, 1, 4, 32);
+    assert_eq!(solve(7, 2).part2, Answer::from(126));
+}
+
+#[test]
+fn day_8() {
+    check(8, 1, 5, 8);
+}
+
+#[test]
+fn day_9() {
+    // The example uses a preamble of 5 rather than 25
+    let numbers: Vec<u64> = example(9, 1).lines().map(|l| l.parse().unwrap()).collect();
+    let invalid = aoc9::solve_part1(&numbers, 5).unwrap();
+    assert_eq!(invalid, 127);
+    assert_eq!(aoc9::solve_part2(&numbers, invalid), Some(62));
+    // In the first six numbers, the one after the preamble is 15 + 25
+    assert!(aoc9::solve_part1(&numbers[..6], 5).is_err());
+}
+
+#[test]
+fn day_10() {
+    check(10, 1, 35, 8);
+    check(10, 2, 220, 19208);
+}
+
+#[test]
+fn day_11() {
+    check(11, 1, 37, 26);
+}
+
+#[test]
+fn day_12() {
+    check(12, 1, 25, 286);
+}
+
+#[test]
+fn day_13() {
+    assert_eq!(solve(13, 1).part1, Answer::from(295));
+}
+
+#[test]
+#[ignore = "part 2 is not solved yet"]
+fn day_13_part_2() {
+    assert_eq!(solve(13, 1).part2, Answer::from(1068781));
+}
+
+#[test]
+fn day_14() {
+    // The first example has too many floating bits to run part 2 on
+    use aoc14::{Chip, DefaultChip};
+
+    let ops: Vec<_> = example(14, 1)
+        .lines()
+        .map(|l| aoc14::parse_line(l).unwrap())
+        .collect();
+    let mut chip: DefaultChip = aoc14::ChipV1::init();
+    chip.run(&ops);
+    assert_eq!(chip.get_memory().count(), 165);
+
+    check(14, 2, 51, 208);
+}
+
+#[test]
+fn day_15() {
+    assert_eq!(solve(15, 1).part1, Answer::from(436));
+}
+
+#[test]
+#[ignore = "part 2 is not solved yet"]
+fn day_15_part_2() {
+    assert_eq!(solve(15, 1).part2, Answer::from(175594));
+}
+
+#[test]
+fn day_16() {
+    assert_eq!(solve(16, 1).part1, Answer::from(71));
+}
+
+#[test]
+#[ignore = "the day is not solved yet"]
+fn day_17() {
+    check(17, 1, 112, 848);
+}
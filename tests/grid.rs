@@ -0,0 +1,109 @@
+//! Checks `Grid2D` and the slopes of 2020 day 3 walked across it on maps small enough to draw.
+
+use advent_of_code::aoc::y2020::aoc3::{self, count_trees, product_of_trees, Slope};
+use advent_of_code::grid::Grid2D;
+
+const MAP: &str = "..#
+#..
+.#.
+";
+
+fn grid(input: &str) -> Grid2D {
+    Grid2D::parse(input).unwrap_or_else(|err| panic!("{}", err))
+}
+
+#[test]
+fn parse_reads_rows_of_equal_width() {
+    let map = grid(MAP);
+    assert_eq!(map.width(), 3);
+    assert_eq!(map.height(), 3);
+    assert_eq!(map.get(2, 0), Some(b'#'));
+    assert_eq!(map.get(0, 1), Some(b'#'));
+    assert_eq!(map.row(2), Some(&b".#."[..]));
+}
+
+#[test]
+fn get_is_none_outside_the_grid() {
+    let map = grid(MAP);
+    assert_eq!(map.get(3, 0), None);
+    assert_eq!(map.get(0, 3), None);
+    assert_eq!(map.get_wrapped(0, 3), None);
+    assert_eq!(map.get_wrapped(7, 100), None);
+    assert_eq!(map.row(3), None);
+}
+
+#[test]
+fn get_wrapped_repeats_past_several_widths() {
+    let map = grid(MAP);
+    for repeat in 0..5 {
+        assert_eq!(map.get_wrapped(2 + 3 * repeat, 0), Some(b'#'));
+        assert_eq!(map.get_wrapped(3 * repeat, 1), Some(b'#'));
+        assert_eq!(map.get_wrapped(1 + 3 * repeat, 1), Some(b'.'));
+    }
+}
+
+#[test]
+fn parse_rejects_uneven_rows_at_their_line() {
+    let err = Grid2D::parse("...\n...\n..\n...\n").unwrap_err();
+    assert_eq!(err.line, Some(3));
+    assert!(err.to_string().contains("row is not as wide as the first"));
+}
+
+#[test]
+fn parse_rejects_empty_input() {
+    let err = Grid2D::parse("").unwrap_err();
+    assert!(err.to_string().contains("empty grid"));
+    assert!(Grid2D::parse("\n...\n").is_err());
+}
+
+#[test]
+fn count_trees_wraps_to_the_right() {
+    let map = grid(MAP);
+    // (0, 0) '.', (3, 1) wraps to (0, 1) '#', (6, 2) wraps to (0, 2) '.'
+    assert_eq!(count_trees(&map, Slope::new(3, 1)).unwrap(), 1);
+    // (0, 0) '.', (2, 1) '.', (4, 2) wraps to (1, 2) '#'
+    assert_eq!(count_trees(&map, Slope::new(2, 1)).unwrap(), 1);
+    // (0, 0) '.', (2, 2) '.'
+    assert_eq!(count_trees(&map, Slope::new(2, 2)).unwrap(), 0);
+}
+
+#[test]
+fn count_trees_rejects_slopes_that_do_not_go_down() {
+    assert!(count_trees(&grid(MAP), Slope::new(1, 0)).is_err());
+}
+
+#[test]
+fn product_of_trees_needs_a_slope() {
+    let map = grid(MAP);
+    assert!(product_of_trees(&map, &[]).is_err());
+    assert_eq!(
+        product_of_trees(&map, &[Slope::new(3, 1), Slope::new(2, 1)]).unwrap(),
+        1
+    );
+    assert!(product_of_trees(&map, &[Slope::new(3, 1), Slope::new(1, 0)]).is_err());
+}
+
+#[test]
+fn solve_multiplies_the_given_slopes_in_part_2_only() {
+    let solution = aoc3::solve(MAP, &[Slope::new(2, 2)]).unwrap();
+    assert_eq!(solution.part1, 1.into());
+    assert_eq!(solution.part2, 0.into());
+    assert!(aoc3::solve(MAP, &[]).is_err());
+
+    // The slopes of one call are not kept for the next, the puzzle's hit no tree going (1, 1)
+    let solution = aoc3::solve(MAP, &[Slope::new(3, 1), Slope::new(2, 1)]).unwrap();
+    assert_eq!(solution.part2, 1.into());
+    let solution = aoc3::aoc_3(MAP).unwrap();
+    assert_eq!(solution.part2, 0.into());
+}
+
+#[test]
+fn slopes_are_parsed_as_right_down_pairs() {
+    assert_eq!(
+        Slope::parse_list("1,1:3,1:1,2").unwrap(),
+        vec![Slope::new(1, 1), Slope::new(3, 1), Slope::new(1, 2)]
+    );
+    assert!(Slope::parse_list("").is_err());
+    assert!(Slope::parse_list("3").is_err());
+    assert!(Slope::parse_list("3,x").is_err());
+}
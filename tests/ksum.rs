@@ -0,0 +1,93 @@
+//! Checks the k-sum search on small lists where the answer can be seen at a glance.
+
+use advent_of_code::ksum;
+
+#[test]
+fn find_with_no_entries_needs_a_zero_target() {
+    assert_eq!(ksum::find(&[1, 2, 3], 0, 0), Some(vec![]));
+    assert_eq!(ksum::find(&[1, 2, 3], 0, 1), None);
+}
+
+#[test]
+fn find_single_entry() {
+    assert_eq!(ksum::find(&[4, 7, 9], 1, 7), Some(vec![7]));
+    assert_eq!(ksum::find(&[4, 7, 9], 1, 8), None);
+}
+
+#[test]
+fn find_pair() {
+    assert_eq!(
+        ksum::find(&[1721, 979, 366, 299, 675, 1456], 2, 2020),
+        Some(vec![299, 1721])
+    );
+    assert_eq!(ksum::find(&[1, 2, 4], 2, 4), None);
+}
+
+#[test]
+fn find_pair_uses_each_position_once() {
+    assert_eq!(ksum::find(&[1010, 1010], 2, 2020), Some(vec![1010, 1010]));
+    assert_eq!(ksum::find(&[1010], 2, 2020), None);
+    assert_eq!(ksum::find(&[1010, 5], 2, 2020), None);
+}
+
+#[test]
+fn find_triple_uses_each_position_once() {
+    assert_eq!(ksum::find(&[2, 2, 2], 3, 6), Some(vec![2, 2, 2]));
+    assert_eq!(ksum::find(&[2, 2, 5], 3, 6), None);
+}
+
+#[test]
+fn find_four() {
+    assert_eq!(
+        ksum::find(&[8, 1, 6, 3, 9, 2], 4, 12),
+        Some(vec![1, 2, 3, 6])
+    );
+    assert_eq!(ksum::find(&[8, 1, 6, 3, 9, 2], 4, 100), None);
+}
+
+#[test]
+fn find_in_list_shorter_than_k() {
+    assert_eq!(ksum::find(&[], 1, 0), None);
+    assert_eq!(ksum::find(&[3], 2, 3), None);
+    assert_eq!(ksum::find(&[1, 2], 3, 3), None);
+    assert_eq!(ksum::find(&[1, 2, 3], 4, 6), None);
+}
+
+#[test]
+fn find_with_negative_entries() {
+    assert_eq!(ksum::find(&[-5, 3, 8], 2, 3), Some(vec![-5, 8]));
+    assert_eq!(ksum::find(&[-4, -1, 7, 2], 3, -3), Some(vec![-4, -1, 2]));
+    assert_eq!(ksum::find(&[-3, 1, 2], 3, 0), Some(vec![-3, 1, 2]));
+    assert_eq!(ksum::find(&[-3, -2, 1, 9], 4, 0), None);
+}
+
+#[test]
+fn find_all_counts_repeated_entries_by_position() {
+    let pairs = ksum::find_all(&[1010, 1010, 1010], 2, 2020);
+    let indices: Vec<Vec<usize>> = pairs.iter().map(|c| c.indices.clone()).collect();
+    assert_eq!(indices, vec![vec![0, 1], vec![0, 2], vec![1, 2]]);
+    assert!(pairs.iter().all(|c| c.values == vec![1010, 1010]));
+}
+
+#[test]
+fn find_all_with_other_k_and_target() {
+    let triples = ksum::find_all(&[5, 1, 4, 2, 3], 3, 9);
+    let indices: Vec<Vec<usize>> = triples.iter().map(|c| c.indices.clone()).collect();
+    assert_eq!(indices, vec![vec![0, 1, 4], vec![2, 3, 4]]);
+    assert_eq!(triples[0].values, vec![5, 1, 3]);
+}
+
+#[test]
+fn find_all_without_combination() {
+    assert!(ksum::find_all(&[1, 2, 4], 2, 4).is_empty());
+    assert!(ksum::find_all(&[1010], 2, 2020).is_empty());
+    assert!(ksum::find_all(&[1, 2], 3, 3).is_empty());
+}
+
+#[test]
+fn find_all_orders_by_position() {
+    let pairs = ksum::find_all(&[1500, 20, 1000, 520, 2000, 1020], 2, 2020);
+    let indices: Vec<Vec<usize>> = pairs.iter().map(|c| c.indices.clone()).collect();
+    assert_eq!(indices, vec![vec![0, 3], vec![1, 4], vec![2, 5]]);
+    assert_eq!(pairs[1].values, vec![20, 2000]);
+}
@@ -0,0 +1,69 @@
+//! Checks that days keeping more than one implementation get the same answers from all of them,
+//! over inputs made by the day's generator. A failing input can be reproduced with `aoc gen`.
+
+use advent_of_code::aoc::y2020::{aoc12, aoc8};
+use advent_of_code::error::parse_lines;
+use advent_of_code::generate::Rng;
+
+const CASES: u64 = 200;
+
+/// Runs `check` on a generated input for every seed, with sizes up to `max_size`.
+/// Small inputs are favoured, being the likeliest to hit edge cases such as looping back to the start.
+/// `check` is given the `aoc gen` options that make the same input.
+fn for_inputs(
+    generate: fn(&mut Rng, Option<usize>) -> String,
+    max_size: usize,
+    check: fn(&str, &str),
+) {
+    let mut sizes = Rng::new(CASES);
+    for seed in 0..CASES {
+        let largest = sizes.below(max_size) + 1;
+        let size = sizes.below(largest) + 1;
+        let input = generate(&mut Rng::new(seed), Some(size));
+        check(&format!("--seed {} --size {}", seed, size), &input);
+    }
+}
+
+#[test]
+fn day_8_part_1_buckets_match_hashset() {
+    for_inputs(aoc8::generate, 4000, |options, input| {
+        let program = aoc8::Program::new(input).unwrap();
+        assert_eq!(
+            aoc8::part_1_buckets(&program),
+            aoc8::part_1_hashset(&program),
+            "{}",
+            options
+        );
+    });
+}
+
+#[test]
+fn day_8_solves_programs_longer_than_its_old_buffers() {
+    // Part 2 used to keep room for 1024 instructions and part 1 for 2048
+    for &size in &[1025, 1100, 2049, 5000] {
+        for seed in 0..5 {
+            let input = aoc8::generate(&mut Rng::new(seed), Some(size));
+            let options = format!("--seed {} --size {}", seed, size);
+            assert!(aoc8::validate(&input).is_empty(), "{}", options);
+            aoc8::aoc_8(&input).unwrap_or_else(|err| panic!("{}: {}", options, err));
+        }
+    }
+}
+
+#[test]
+fn day_12_compact_matches_long_solution() {
+    for_inputs(aoc12::generate, 1000, |options, input| {
+        let lines: Vec<String> = input.lines().map(|l| l.to_string()).collect();
+        let ops = parse_lines(input.lines(), aoc12::parse_compact).unwrap();
+        let compact = (
+            aoc12::compact_solution_1(&ops),
+            aoc12::compact_solution_2(&ops),
+        );
+        assert_eq!(
+            compact,
+            aoc12::long_solution(&lines).unwrap(),
+            "{}",
+            options
+        );
+    });
+}
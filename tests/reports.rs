@@ -0,0 +1,130 @@
+//! Checks the reports of the days that explain their answers, on inputs small enough to follow.
+
+use advent_of_code::aoc::{find_day, DayId};
+use advent_of_code::output::Table;
+
+fn report(day: u32, input: &str) -> Table {
+    let id = DayId::new(2020, day);
+    let reporter = find_day(id)
+        .and_then(|day| day.reporter)
+        .expect("day has a report");
+    reporter(input).unwrap_or_else(|err| panic!("{}", err.for_day(id)))
+}
+
+fn column(table: &Table, name: &str) -> Vec<String> {
+    let i = table
+        .columns
+        .iter()
+        .position(|&column| column == name)
+        .unwrap_or_else(|| panic!("no column {}", name));
+    table.rows.iter().map(|row| row[i].clone()).collect()
+}
+
+#[test]
+fn day_1_report_lists_every_pair() {
+    let table = report(1, "1000\n500\n1020\n1500\n520\n7\n");
+    let part1: Vec<&Vec<String>> = table.rows.iter().filter(|row| row[0] == "1").collect();
+    assert_eq!(part1.len(), 2);
+
+    assert_eq!(part1[0][1], "1 of 2");
+    assert_eq!(part1[0][2], "1, 3");
+    assert_eq!(part1[0][3], "1000 + 1020");
+    assert_eq!(part1[0][4], "1020000");
+    assert_eq!(part1[1][1], "2 of 2");
+    assert_eq!(part1[1][2], "4, 5");
+    assert_eq!(part1[1][3], "1500 + 520");
+    assert!(part1
+        .iter()
+        .all(|row| row[5] == "ambiguous, 2 combinations sum to 2020"));
+}
+
+#[test]
+fn day_1_report_without_combination() {
+    let table = report(1, "1721\n299\n");
+    assert_eq!(column(&table, "part"), vec!["1", "2"]);
+    assert_eq!(column(&table, "solution"), vec!["1 of 1", "-"]);
+    assert_eq!(column(&table, "note"), vec!["", "no 3 entries sum to 2020"]);
+}
+
+const DAY_2: &str = "1-3 a: abcde
+1-3 b: cdefg
+2-9 c: ccccccccc
+nonsense
+";
+
+#[test]
+fn day_2_report_explains_each_verdict() {
+    let table = report(2, DAY_2);
+    assert_eq!(column(&table, "line"), vec!["1", "2", "3", "4"]);
+    assert_eq!(
+        column(&table, "part1"),
+        vec!["pass", "fail", "pass", "error"]
+    );
+    assert_eq!(
+        column(&table, "part1_reason"),
+        vec![
+            "'a' appears 1 times, allowed 1-3",
+            "'b' appears 0 times, allowed 1-3",
+            "'c' appears 9 times, allowed 2-9",
+            "expected ':' in 'nonsense'",
+        ]
+    );
+    assert_eq!(
+        column(&table, "part2"),
+        vec!["pass", "fail", "fail", "error"]
+    );
+    assert_eq!(
+        column(&table, "part2_reason"),
+        vec![
+            "only position 1 contains 'a'",
+            "neither position 1 nor 3 contains 'b'",
+            "positions 2 and 9 both contain 'c'",
+            "expected ':' in 'nonsense'",
+        ]
+    );
+}
+
+#[test]
+fn day_2_report_rejects_position_0_in_part_2_only() {
+    let table = report(2, "0-2 a: ab\n");
+    assert_eq!(column(&table, "part1"), vec!["pass"]);
+    assert_eq!(column(&table, "part2"), vec!["error"]);
+    assert_eq!(
+        column(&table, "part2_reason"),
+        vec!["positions start at 1, found '0'"]
+    );
+}
+
+#[test]
+fn table_quotes_csv_fields_with_commas_and_quotes() {
+    let mut table = Table::new(vec!["line", "reason"]);
+    table.push(vec![
+        "1".to_string(),
+        "appears 1 times, allowed 1-3".to_string(),
+    ]);
+    table.push(vec!["2".to_string(), "a \"quoted\" word".to_string()]);
+    table.push(vec!["3".to_string(), "plain".to_string()]);
+
+    assert_eq!(
+        table.to_csv(),
+        "line,reason\n1,\"appears 1 times, allowed 1-3\"\n2,\"a \"\"quoted\"\" word\"\n3,plain"
+    );
+}
+
+#[test]
+fn table_aligns_text_and_writes_json_strings() {
+    let mut table = Table::new(vec!["line", "reason"]);
+    table.push(vec!["10".to_string(), "a \"quoted\" word".to_string()]);
+    table.push(vec!["2".to_string(), "".to_string()]);
+
+    assert_eq!(table.to_text(), "line  reason\n10    a \"quoted\" word\n2");
+    assert_eq!(
+        table.to_json(),
+        concat!(
+            "[\n",
+            "  {\"line\": \"10\", \"reason\": \"a \\\"quoted\\\" word\"},\n",
+            "  {\"line\": \"2\", \"reason\": \"\"}\n",
+            "]"
+        )
+    );
+}
@@ -0,0 +1,132 @@
+//! Adds days to a copy of the 2020 module in a temporary directory, checking what `aoc new` writes.
+
+use std::fs;
+use std::path::PathBuf;
+
+use advent_of_code::aoc::DayId;
+use advent_of_code::scaffold::Project;
+
+/// A project holding only the module of 2020, removed again when dropped
+struct TempProject {
+    project: Project,
+}
+
+impl TempProject {
+    fn new(name: &str) -> TempProject {
+        let root =
+            std::env::temp_dir().join(format!("aoc_scaffold_{}_{}", name, std::process::id()));
+        let _ = fs::remove_dir_all(&root);
+        fs::create_dir_all(root.join("src/aoc/y2020")).unwrap();
+        fs::copy("src/aoc/y2020/mod.rs", root.join("src/aoc/y2020/mod.rs")).unwrap();
+
+        TempProject {
+            project: Project::new(&root, root.join("inputs")),
+        }
+    }
+
+    fn path(&self, path: &str) -> PathBuf {
+        self.project.root.join(path)
+    }
+
+    fn read(&self, path: &str) -> String {
+        fs::read_to_string(self.path(path)).unwrap()
+    }
+}
+
+impl Drop for TempProject {
+    fn drop(&mut self) {
+        let _ = fs::remove_dir_all(&self.project.root);
+    }
+}
+
+#[test]
+fn new_day_is_registered_in_order() {
+    let temp = TempProject::new("order");
+    temp.project
+        .new_day(DayId::new(2020, 20), "Jurassic Jigsaw")
+        .unwrap();
+    temp.project
+        .new_day(DayId::new(2020, 18), "Operation Order")
+        .unwrap();
+
+    let registry = temp.read("src/aoc/y2020/mod.rs");
+    let position = |text: &str| {
+        registry
+            .find(text)
+            .unwrap_or_else(|| panic!("{} missing", text))
+    };
+    assert!(position("pub mod aoc17;") < position("pub mod aoc18;"));
+    assert!(position("pub mod aoc18;") < position("pub mod aoc20;"));
+    assert!(position("Day::new(17,") < position("Day::new(18,"));
+    assert!(position("Day::new(18,") < position("Day::new(20,"));
+    assert!(registry
+        .contains("    Day::new(18, \"Operation Order\", aoc18::aoc_18, aoc18::validate),\n"));
+}
+
+#[test]
+fn new_day_creates_solver_inputs_and_test() {
+    let temp = TempProject::new("files");
+    let written = temp
+        .project
+        .new_day(DayId::new(2020, 18), "Operation Order")
+        .unwrap();
+
+    for path in &[
+        "src/aoc/y2020/aoc18.rs",
+        "src/aoc/y2020/mod.rs",
+        "tests/examples_2020.rs",
+        "inputs/2020/aoc_18_input.txt",
+        "inputs/2020/examples/aoc_18_example1.txt",
+    ] {
+        assert!(written.contains(&temp.path(path)), "{} not written", path);
+    }
+    assert!(temp
+        .read("src/aoc/y2020/aoc18.rs")
+        .contains("pub fn aoc_18(input: &str)"));
+    assert!(temp
+        .read("tests/examples_2020.rs")
+        .contains("fn day_18() {"));
+    assert_eq!(temp.read("inputs/2020/aoc_18_input.txt"), "");
+}
+
+#[test]
+fn new_day_keeps_existing_inputs() {
+    let temp = TempProject::new("inputs");
+    fs::create_dir_all(temp.path("inputs/2020")).unwrap();
+    fs::write(temp.path("inputs/2020/aoc_18_input.txt"), "1 + 2").unwrap();
+
+    let written = temp
+        .project
+        .new_day(DayId::new(2020, 18), "Operation Order")
+        .unwrap();
+    assert!(!written.contains(&temp.path("inputs/2020/aoc_18_input.txt")));
+    assert_eq!(temp.read("inputs/2020/aoc_18_input.txt"), "1 + 2");
+}
+
+#[test]
+fn new_day_rejects_existing_and_invalid_days() {
+    let temp = TempProject::new("rejects");
+    let registry = temp.read("src/aoc/y2020/mod.rs");
+
+    assert!(temp
+        .project
+        .new_day(DayId::new(2020, 7), "Handy Haversacks")
+        .is_err());
+    assert!(temp
+        .project
+        .new_day(DayId::new(2020, 26), "Too Late")
+        .is_err());
+    assert!(temp
+        .project
+        .new_day(DayId::new(2021, 1), "No Module")
+        .is_err());
+    assert_eq!(temp.read("src/aoc/y2020/mod.rs"), registry);
+
+    temp.project
+        .new_day(DayId::new(2020, 18), "Operation Order")
+        .unwrap();
+    assert!(temp
+        .project
+        .new_day(DayId::new(2020, 18), "Operation Order")
+        .is_err());
+}
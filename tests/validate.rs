@@ -0,0 +1,24 @@
+//! Checks that validators reject inputs their solver cannot run, at the line of the problem.
+
+use advent_of_code::aoc::y2020::aoc8;
+
+#[test]
+fn day_8_accepts_jumps_and_nops_within_the_program() {
+    // Landing right after the last instruction ends the program
+    assert!(aoc8::validate("nop +3\njmp +2\nacc +1\njmp -3\n").is_empty());
+}
+
+#[test]
+fn day_8_rejects_jumps_and_nops_outside_the_program() {
+    let problems = aoc8::validate("nop -1\nacc +1\njmp +2\nnop +2\njmp -5\n");
+    let lines: Vec<Option<usize>> = problems.iter().map(|problem| problem.line).collect();
+    assert_eq!(lines, vec![Some(1), Some(5)]);
+    assert!(problems[0].to_string().contains("nop would land outside"));
+    assert!(problems[1].to_string().contains("jump lands outside"));
+}
+
+#[test]
+fn day_8_accepts_programs_of_any_length() {
+    let input = "acc +1\n".repeat(3000) + "jmp -3000\n";
+    assert!(aoc8::validate(&input).is_empty());
+}